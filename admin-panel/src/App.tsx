@@ -1,6 +1,6 @@
 import { useState, useEffect } from 'react';
 import './App.css';
-import { DbConnection, CrewTemplate, ItemComponent, CompletedItem } from './autobindings';
+import { DbConnection, CrewTemplate, ItemComponent, CompletedItem, TemplateSyncPolicy } from './autobindings';
 
 function App() {
   const [activeTab, setActiveTab] = useState<'crew' | 'items' | 'completed'>('crew');
@@ -157,7 +157,11 @@ function CrewTemplatesPanel({ connection }: { connection: DbConnection | null })
       cost: field === 'cost' ? value : undefined,
     };
 
-    connection.reducers.adminUpdateCrewTemplate(templateId, updates.name, updates.maxHp, updates.attack, updates.defense, updates.cost);
+    // Open shop offers pick up the edit, crew already bought keep their stats
+    connection.reducers.adminUpdateCrewTemplate(
+      templateId, updates.name, updates.maxHp, updates.attack, updates.defense, updates.cost,
+      undefined, undefined, undefined, undefined, undefined, TemplateSyncPolicy.ResyncShop
+    );
   };
 
   return (
@@ -248,18 +252,13 @@ function CrewTemplatesPanel({ connection }: { connection: DbConnection | null })
 function ItemComponentsPanel({ connection }: { connection: any }) {
   return (
     <div className="panel">
-      <h2>Item Components (8 Base Items)</h2>
+      <h2>Item Components (3 Base Items)</h2>
       <p className="hint">Adjust stat bonuses for each base item component.</p>
 
       <div className="grid">
-        <ItemComponentCard name="Cutlass" stat="AD" value={10} />
-        <ItemComponentCard name="Sniper Goggles" stat="Crit %" value={15} />
-        <ItemComponentCard name="Shell Dial" stat="AS %" value={15} />
-        <ItemComponentCard name="Tone Dial" stat="AP" value={15} />
-        <ItemComponentCard name="Seastone Fragment" stat="Armor" value={10} />
-        <ItemComponentCard name="Tidal Cloak" stat="MR" value={10} />
-        <ItemComponentCard name="Energy Drink" stat="Mana" value={20} />
-        <ItemComponentCard name="Meat" stat="HP" value={50} />
+        <ItemComponentCard name="Sword" stat="AD" value={4} />
+        <ItemComponentCard name="Ring" stat="AP" value={5} />
+        <ItemComponentCard name="Gloves" stat="AS %" value={10} />
       </div>
     </div>
   );
@@ -299,7 +298,7 @@ function ItemComponentCard({ name, stat, value }: { name: string; stat: string;
 function CompletedItemsPanel({ connection }: { connection: any }) {
   return (
     <div className="panel">
-      <h2>Completed Items (15 Combinations)</h2>
+      <h2>Completed Items (6 Combinations)</h2>
       <p className="hint">Edit final item stats including special effects like splash damage, crit damage multipliers, etc.</p>
 
       <div className="completed-items">
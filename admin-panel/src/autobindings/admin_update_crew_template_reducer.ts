@@ -27,6 +27,16 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
 } from "spacetimedb";
 
+import { CrewRarity } from "./crew_rarity_type";
+// Mark import as potentially unused
+declare type __keep_CrewRarity = CrewRarity;
+import { CrewTrait } from "./crew_trait_type";
+// Mark import as potentially unused
+declare type __keep_CrewTrait = CrewTrait;
+import { TemplateSyncPolicy } from "./template_sync_policy_type";
+// Mark import as potentially unused
+declare type __keep_TemplateSyncPolicy = TemplateSyncPolicy;
+
 export type AdminUpdateCrewTemplate = {
   templateId: bigint,
   name: string | undefined,
@@ -34,6 +44,12 @@ export type AdminUpdateCrewTemplate = {
   attack: number | undefined,
   defense: number | undefined,
   cost: number | undefined,
+  rarity: CrewRarity | undefined,
+  traits: CrewTrait[] | undefined,
+  abilityPower: number | undefined,
+  attackSpeed: number | undefined,
+  magicResistance: number | undefined,
+  syncPolicy: TemplateSyncPolicy,
 };
 /**
  * An object for generated helper functions.
@@ -52,6 +68,12 @@ export const AdminUpdateCrewTemplate = {
         { name: "attack", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U32)},
         { name: "defense", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U32)},
         { name: "cost", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U32)},
+        { name: "rarity", algebraicType: __AlgebraicTypeValue.createOptionType(CrewRarity.getTypeScriptAlgebraicType())},
+        { name: "traits", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.Array(CrewTrait.getTypeScriptAlgebraicType()))},
+        { name: "abilityPower", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U32)},
+        { name: "attackSpeed", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.F32)},
+        { name: "magicResistance", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U32)},
+        { name: "syncPolicy", algebraicType: TemplateSyncPolicy.getTypeScriptAlgebraicType()},
       ]
     });
   },
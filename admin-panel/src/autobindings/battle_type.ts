@@ -39,6 +39,14 @@ export type Battle = {
   status: BattleStatus,
   turn: number,
   bountyReward: number,
+  player1Bounty: number,
+  player2Bounty: number,
+  seed: bigint,
+  rngState: bigint,
+  lobbyId: bigint,
+  round: number,
+  settled: boolean,
+  ghost: boolean,
 };
 /**
  * An object for generated helper functions.
@@ -58,6 +66,14 @@ export const Battle = {
         { name: "status", algebraicType: BattleStatus.getTypeScriptAlgebraicType()},
         { name: "turn", algebraicType: __AlgebraicTypeValue.U32},
         { name: "bountyReward", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "player1Bounty", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "player2Bounty", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "seed", algebraicType: __AlgebraicTypeValue.U64},
+        { name: "rngState", algebraicType: __AlgebraicTypeValue.U64},
+        { name: "lobbyId", algebraicType: __AlgebraicTypeValue.U64},
+        { name: "round", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "settled", algebraicType: __AlgebraicTypeValue.Bool},
+        { name: "ghost", algebraicType: __AlgebraicTypeValue.Bool},
       ]
     });
   },
@@ -29,21 +29,12 @@ import {
 import * as CompletedItemVariants from './completed_item_variants'
 
 // The tagged union or sum type for the algebraic type `CompletedItem`.
-export type CompletedItem = CompletedItemVariants.Yoru |
+export type CompletedItem = CompletedItemVariants.Yooru |
   CompletedItemVariants.Kabuto |
   CompletedItemVariants.Shusui |
-  CompletedItemVariants.ClimaTact |
-  CompletedItemVariants.ThunderTempo |
-  CompletedItemVariants.MirageFlower |
-  CompletedItemVariants.AdamWood |
-  CompletedItemVariants.SeaKingScale |
-  CompletedItemVariants.ThousandSunnyHull |
-  CompletedItemVariants.VivrCard |
-  CompletedItemVariants.LogPose |
-  CompletedItemVariants.Poneglyph |
-  CompletedItemVariants.GumGumFruit |
-  CompletedItemVariants.GomuGomuNoMi |
-  CompletedItemVariants.HakiMastery;
+  CompletedItemVariants.RingRing |
+  CompletedItemVariants.TenTonHammer |
+  CompletedItemVariants.ImpactDial;
 
 // A value with helper functions to construct the type.
 export const CompletedItem = {
@@ -53,40 +44,22 @@ export const CompletedItem = {
   // assert!(foo.tag === "A");
   // assert!(foo.value === 42);
   // ```
-  Yoru: { tag: "Yoru" } as const,
+  Yooru: { tag: "Yooru" } as const,
   Kabuto: { tag: "Kabuto" } as const,
   Shusui: { tag: "Shusui" } as const,
-  ClimaTact: { tag: "ClimaTact" } as const,
-  ThunderTempo: { tag: "ThunderTempo" } as const,
-  MirageFlower: { tag: "MirageFlower" } as const,
-  AdamWood: { tag: "AdamWood" } as const,
-  SeaKingScale: { tag: "SeaKingScale" } as const,
-  ThousandSunnyHull: { tag: "ThousandSunnyHull" } as const,
-  VivrCard: { tag: "VivrCard" } as const,
-  LogPose: { tag: "LogPose" } as const,
-  Poneglyph: { tag: "Poneglyph" } as const,
-  GumGumFruit: { tag: "GumGumFruit" } as const,
-  GomuGomuNoMi: { tag: "GomuGomuNoMi" } as const,
-  HakiMastery: { tag: "HakiMastery" } as const,
+  RingRing: { tag: "RingRing" } as const,
+  TenTonHammer: { tag: "TenTonHammer" } as const,
+  ImpactDial: { tag: "ImpactDial" } as const,
 
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
     return __AlgebraicTypeValue.Sum({
       variants: [
-        { name: "Yoru", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "Yooru", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "Kabuto", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "Shusui", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "ClimaTact", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "ThunderTempo", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "MirageFlower", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "AdamWood", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "SeaKingScale", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "ThousandSunnyHull", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "VivrCard", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "LogPose", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "Poneglyph", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "GumGumFruit", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "GomuGomuNoMi", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "HakiMastery", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "RingRing", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "TenTonHammer", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "ImpactDial", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
       ]
     });
   },
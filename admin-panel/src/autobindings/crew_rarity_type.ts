@@ -30,6 +30,7 @@ import * as CrewRarityVariants from './crew_rarity_variants'
 
 // The tagged union or sum type for the algebraic type `CrewRarity`.
 export type CrewRarity = CrewRarityVariants.Common |
+  CrewRarityVariants.Uncommon |
   CrewRarityVariants.Rare |
   CrewRarityVariants.Epic |
   CrewRarityVariants.Legendary;
@@ -43,6 +44,7 @@ export const CrewRarity = {
   // assert!(foo.value === 42);
   // ```
   Common: { tag: "Common" } as const,
+  Uncommon: { tag: "Uncommon" } as const,
   Rare: { tag: "Rare" } as const,
   Epic: { tag: "Epic" } as const,
   Legendary: { tag: "Legendary" } as const,
@@ -51,6 +53,7 @@ export const CrewRarity = {
     return __AlgebraicTypeValue.Sum({
       variants: [
         { name: "Common", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "Uncommon", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "Rare", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "Epic", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "Legendary", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
@@ -27,12 +27,9 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
 } from "spacetimedb";
 import { Crew } from "./crew_type";
-import { ItemComponent } from "./item_component_type";
+import { Item } from "./item_type";
 // Mark import as potentially unused
-declare type __keep_ItemComponent = ItemComponent;
-import { CompletedItem } from "./completed_item_type";
-// Mark import as potentially unused
-declare type __keep_CompletedItem = CompletedItem;
+declare type __keep_Item = Item;
 import { CrewRarity } from "./crew_rarity_type";
 // Mark import as potentially unused
 declare type __keep_CrewRarity = CrewRarity;
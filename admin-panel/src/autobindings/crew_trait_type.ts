@@ -30,17 +30,23 @@ import * as CrewTraitVariants from './crew_trait_variants'
 
 // The tagged union or sum type for the algebraic type `CrewTrait`.
 export type CrewTrait = CrewTraitVariants.StrawHat |
-  CrewTraitVariants.Marine |
   CrewTraitVariants.Revolutionary |
   CrewTraitVariants.RedHairPirates |
   CrewTraitVariants.Giants |
   CrewTraitVariants.HolyKnights |
-  CrewTraitVariants.FiveElders |
+  CrewTraitVariants.Gorosei |
+  CrewTraitVariants.BlackbearedPirates |
+  CrewTraitVariants.WhitebearedPirates |
+  CrewTraitVariants.BigMomPirates |
+  CrewTraitVariants.HeartPirates |
+  CrewTraitVariants.CrossGuildPirates |
   CrewTraitVariants.Logia |
   CrewTraitVariants.Paramecia |
   CrewTraitVariants.Zoan |
-  CrewTraitVariants.Sword |
-  CrewTraitVariants.DfUser;
+  CrewTraitVariants.Swordsman |
+  CrewTraitVariants.Brawler |
+  CrewTraitVariants.Sniper |
+  CrewTraitVariants.Emperor;
 
 // A value with helper functions to construct the type.
 export const CrewTrait = {
@@ -51,33 +57,45 @@ export const CrewTrait = {
   // assert!(foo.value === 42);
   // ```
   StrawHat: { tag: "StrawHat" } as const,
-  Marine: { tag: "Marine" } as const,
   Revolutionary: { tag: "Revolutionary" } as const,
   RedHairPirates: { tag: "RedHairPirates" } as const,
   Giants: { tag: "Giants" } as const,
   HolyKnights: { tag: "HolyKnights" } as const,
-  FiveElders: { tag: "FiveElders" } as const,
+  Gorosei: { tag: "Gorosei" } as const,
+  BlackbearedPirates: { tag: "BlackbearedPirates" } as const,
+  WhitebearedPirates: { tag: "WhitebearedPirates" } as const,
+  BigMomPirates: { tag: "BigMomPirates" } as const,
+  HeartPirates: { tag: "HeartPirates" } as const,
+  CrossGuildPirates: { tag: "CrossGuildPirates" } as const,
   Logia: { tag: "Logia" } as const,
   Paramecia: { tag: "Paramecia" } as const,
   Zoan: { tag: "Zoan" } as const,
-  Sword: { tag: "Sword" } as const,
-  DFUser: { tag: "DFUser" } as const,
+  Swordsman: { tag: "Swordsman" } as const,
+  Brawler: { tag: "Brawler" } as const,
+  Sniper: { tag: "Sniper" } as const,
+  Emperor: { tag: "Emperor" } as const,
 
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
     return __AlgebraicTypeValue.Sum({
       variants: [
         { name: "StrawHat", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "Marine", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "Revolutionary", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "RedHairPirates", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "Giants", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "HolyKnights", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "FiveElders", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "Gorosei", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "BlackbearedPirates", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "WhitebearedPirates", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "BigMomPirates", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "HeartPirates", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "CrossGuildPirates", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "Logia", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "Paramecia", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "Zoan", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "Sword", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "DFUser", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "Swordsman", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "Brawler", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "Sniper", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "Emperor", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
       ]
     });
   },
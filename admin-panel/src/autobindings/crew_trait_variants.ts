@@ -28,15 +28,21 @@ import {
 } from "spacetimedb";
 
 export type StrawHat = { tag: "StrawHat" };
-export type Marine = { tag: "Marine" };
 export type Revolutionary = { tag: "Revolutionary" };
 export type RedHairPirates = { tag: "RedHairPirates" };
 export type Giants = { tag: "Giants" };
 export type HolyKnights = { tag: "HolyKnights" };
-export type FiveElders = { tag: "FiveElders" };
+export type Gorosei = { tag: "Gorosei" };
+export type BlackbearedPirates = { tag: "BlackbearedPirates" };
+export type WhitebearedPirates = { tag: "WhitebearedPirates" };
+export type BigMomPirates = { tag: "BigMomPirates" };
+export type HeartPirates = { tag: "HeartPirates" };
+export type CrossGuildPirates = { tag: "CrossGuildPirates" };
 export type Logia = { tag: "Logia" };
 export type Paramecia = { tag: "Paramecia" };
 export type Zoan = { tag: "Zoan" };
-export type Sword = { tag: "Sword" };
-export type DfUser = { tag: "DfUser" };
+export type Swordsman = { tag: "Swordsman" };
+export type Brawler = { tag: "Brawler" };
+export type Sniper = { tag: "Sniper" };
+export type Emperor = { tag: "Emperor" };
 
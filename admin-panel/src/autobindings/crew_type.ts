@@ -26,12 +26,9 @@ import {
   type ReducerEventContextInterface as __ReducerEventContextInterface,
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
 } from "spacetimedb";
-import { ItemComponent } from "./item_component_type";
+import { Item } from "./item_type";
 // Mark import as potentially unused
-declare type __keep_ItemComponent = ItemComponent;
-import { CompletedItem } from "./completed_item_type";
-// Mark import as potentially unused
-declare type __keep_CompletedItem = CompletedItem;
+declare type __keep_Item = Item;
 import { CrewRarity } from "./crew_rarity_type";
 // Mark import as potentially unused
 declare type __keep_CrewRarity = CrewRarity;
@@ -45,17 +42,21 @@ export type Crew = {
   owner: __Identity,
   name: string,
   rarity: CrewRarity,
-  trait1: CrewTrait,
-  trait2: CrewTrait | undefined,
+  traits: CrewTrait[],
   maxHp: number,
-  currentHp: number,
+  abilityPower: number,
   attack: number,
+  attackSpeed: number,
   defense: number,
+  magicResistance: number,
   level: number,
   slotIndex: number | undefined,
-  item1: ItemComponent | undefined,
-  item2: ItemComponent | undefined,
-  completedItem: CompletedItem | undefined,
+  benchIndex: number | undefined,
+  item1: Item | undefined,
+  item2: Item | undefined,
+  item3: Item | undefined,
+  templateId: bigint,
+  cost: number,
 };
 /**
  * An object for generated helper functions.
@@ -72,17 +73,21 @@ export const Crew = {
         { name: "owner", algebraicType: __AlgebraicTypeValue.createIdentityType()},
         { name: "name", algebraicType: __AlgebraicTypeValue.String},
         { name: "rarity", algebraicType: CrewRarity.getTypeScriptAlgebraicType()},
-        { name: "trait1", algebraicType: CrewTrait.getTypeScriptAlgebraicType()},
-        { name: "trait2", algebraicType: __AlgebraicTypeValue.createOptionType(CrewTrait.getTypeScriptAlgebraicType())},
+        { name: "traits", algebraicType: __AlgebraicTypeValue.Array(CrewTrait.getTypeScriptAlgebraicType())},
         { name: "maxHp", algebraicType: __AlgebraicTypeValue.U32},
-        { name: "currentHp", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "abilityPower", algebraicType: __AlgebraicTypeValue.U32},
         { name: "attack", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "attackSpeed", algebraicType: __AlgebraicTypeValue.F32},
         { name: "defense", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "magicResistance", algebraicType: __AlgebraicTypeValue.U32},
         { name: "level", algebraicType: __AlgebraicTypeValue.U8},
         { name: "slotIndex", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U8)},
-        { name: "item1", algebraicType: __AlgebraicTypeValue.createOptionType(ItemComponent.getTypeScriptAlgebraicType())},
-        { name: "item2", algebraicType: __AlgebraicTypeValue.createOptionType(ItemComponent.getTypeScriptAlgebraicType())},
-        { name: "completedItem", algebraicType: __AlgebraicTypeValue.createOptionType(CompletedItem.getTypeScriptAlgebraicType())},
+        { name: "benchIndex", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U8)},
+        { name: "item1", algebraicType: __AlgebraicTypeValue.createOptionType(Item.getTypeScriptAlgebraicType())},
+        { name: "item2", algebraicType: __AlgebraicTypeValue.createOptionType(Item.getTypeScriptAlgebraicType())},
+        { name: "item3", algebraicType: __AlgebraicTypeValue.createOptionType(Item.getTypeScriptAlgebraicType())},
+        { name: "templateId", algebraicType: __AlgebraicTypeValue.U64},
+        { name: "cost", algebraicType: __AlgebraicTypeValue.U32},
       ]
     });
   },
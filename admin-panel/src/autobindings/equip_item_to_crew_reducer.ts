@@ -27,13 +27,9 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
 } from "spacetimedb";
 
-import { ItemComponent } from "./item_component_type";
-// Mark import as potentially unused
-declare type __keep_ItemComponent = ItemComponent;
-
 export type EquipItemToCrew = {
   crewId: bigint,
-  component: ItemComponent,
+  playerItemId: bigint,
 };
 /**
  * An object for generated helper functions.
@@ -47,7 +43,7 @@ export const EquipItemToCrew = {
     return __AlgebraicTypeValue.Product({
       elements: [
         { name: "crewId", algebraicType: __AlgebraicTypeValue.U64},
-        { name: "component", algebraicType: ItemComponent.getTypeScriptAlgebraicType()},
+        { name: "playerItemId", algebraicType: __AlgebraicTypeValue.U64},
       ]
     });
   },
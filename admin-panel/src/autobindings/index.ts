@@ -30,62 +30,160 @@ import {
 } from "spacetimedb";
 
 // Import and reexport all reducer arg types
+import { AddItemToInventory } from "./add_item_to_inventory_reducer.ts";
+export { AddItemToInventory };
+import { AdminAddCrewTemplate } from "./admin_add_crew_template_reducer.ts";
+export { AdminAddCrewTemplate };
 import { AdminAddRecipe } from "./admin_add_recipe_reducer.ts";
 export { AdminAddRecipe };
+import { AdminDeleteCrewTemplate } from "./admin_delete_crew_template_reducer.ts";
+export { AdminDeleteCrewTemplate };
 import { AdminDeleteRecipe } from "./admin_delete_recipe_reducer.ts";
 export { AdminDeleteRecipe };
+import { AdminReimportCrewTemplates } from "./admin_reimport_crew_templates_reducer.ts";
+export { AdminReimportCrewTemplates };
+import { AdminRunMigrations } from "./admin_run_migrations_reducer.ts";
+export { AdminRunMigrations };
 import { AdminUpdateCompletedItemStats } from "./admin_update_completed_item_stats_reducer.ts";
 export { AdminUpdateCompletedItemStats };
 import { AdminUpdateCrewTemplate } from "./admin_update_crew_template_reducer.ts";
 export { AdminUpdateCrewTemplate };
+import { AdminUpdateGameConfig } from "./admin_update_game_config_reducer.ts";
+export { AdminUpdateGameConfig };
 import { AdminUpdateItemComponentStats } from "./admin_update_item_component_stats_reducer.ts";
 export { AdminUpdateItemComponentStats };
+import { AdminUpdateLevelThreshold } from "./admin_update_level_threshold_reducer.ts";
+export { AdminUpdateLevelThreshold };
 import { AdminUpdateRecipe } from "./admin_update_recipe_reducer.ts";
 export { AdminUpdateRecipe };
+import { AdminUpdateShopOdds } from "./admin_update_shop_odds_reducer.ts";
+export { AdminUpdateShopOdds };
+import { AdvanceRound } from "./advance_round_reducer.ts";
+export { AdvanceRound };
+import { BattleTick } from "./battle_tick_reducer.ts";
+export { BattleTick };
 import { BuyCrew } from "./buy_crew_reducer.ts";
 export { BuyCrew };
+import { BuyXp } from "./buy_xp_reducer.ts";
+export { BuyXp };
 import { ClientConnected } from "./client_connected_reducer.ts";
 export { ClientConnected };
 import { ClientDisconnected } from "./client_disconnected_reducer.ts";
 export { ClientDisconnected };
+import { CompleteBattle } from "./complete_battle_reducer.ts";
+export { CompleteBattle };
+import { CreateLobby } from "./create_lobby_reducer.ts";
+export { CreateLobby };
 import { EquipItemToCrew } from "./equip_item_to_crew_reducer.ts";
 export { EquipItemToCrew };
+import { GrantAdmin } from "./grant_admin_reducer.ts";
+export { GrantAdmin };
+import { JoinLobby } from "./join_lobby_reducer.ts";
+export { JoinLobby };
+import { LeaveLobby } from "./leave_lobby_reducer.ts";
+export { LeaveLobby };
 import { MoveCrew } from "./move_crew_reducer.ts";
 export { MoveCrew };
+import { MoveToBench } from "./move_to_bench_reducer.ts";
+export { MoveToBench };
+import { MoveToField } from "./move_to_field_reducer.ts";
+export { MoveToField };
+import { PhaseDeadline } from "./phase_deadline_reducer.ts";
+export { PhaseDeadline };
 import { RefreshShop } from "./refresh_shop_reducer.ts";
 export { RefreshShop };
 import { RegisterPlayer } from "./register_player_reducer.ts";
 export { RegisterPlayer };
 import { RemoveItemFromCrew } from "./remove_item_from_crew_reducer.ts";
 export { RemoveItemFromCrew };
+import { RevokeAdmin } from "./revoke_admin_reducer.ts";
+export { RevokeAdmin };
+import { SellCrew } from "./sell_crew_reducer.ts";
+export { SellCrew };
+import { SetItemBenchSlot } from "./set_item_bench_slot_reducer.ts";
+export { SetItemBenchSlot };
+import { StarUpCrew } from "./star_up_crew_reducer.ts";
+export { StarUpCrew };
 import { StartBattle } from "./start_battle_reducer.ts";
 export { StartBattle };
+import { StartLobby } from "./start_lobby_reducer.ts";
+export { StartLobby };
+import { SwapCrew } from "./swap_crew_reducer.ts";
+export { SwapCrew };
+import { ToggleShopLock } from "./toggle_shop_lock_reducer.ts";
+export { ToggleShopLock };
+import { UpdateShip } from "./update_ship_reducer.ts";
+export { UpdateShip };
 
 // Import and reexport all table handle types
+import { AdminTableHandle } from "./admin_table.ts";
+export { AdminTableHandle };
 import { BattleTableHandle } from "./battle_table.ts";
 export { BattleTableHandle };
+import { BattleTickTimerTableHandle } from "./battle_tick_timer_table.ts";
+export { BattleTickTimerTableHandle };
+import { BattleUnitTableHandle } from "./battle_unit_table.ts";
+export { BattleUnitTableHandle };
+import { ChampionPoolTableHandle } from "./champion_pool_table.ts";
+export { ChampionPoolTableHandle };
 import { CompletedItemStatsTableHandle } from "./completed_item_stats_table.ts";
 export { CompletedItemStatsTableHandle };
 import { CrewTableHandle } from "./crew_table.ts";
 export { CrewTableHandle };
 import { CrewTemplateTableHandle } from "./crew_template_table.ts";
 export { CrewTemplateTableHandle };
+import { EffectiveStatsTableHandle } from "./effective_stats_table.ts";
+export { EffectiveStatsTableHandle };
+import { EnemyTableHandle } from "./enemy_table.ts";
+export { EnemyTableHandle };
+import { GameConfigTableHandle } from "./game_config_table.ts";
+export { GameConfigTableHandle };
+import { GameSessionTableHandle } from "./game_session_table.ts";
+export { GameSessionTableHandle };
 import { ItemCombinationRecipeTableHandle } from "./item_combination_recipe_table.ts";
 export { ItemCombinationRecipeTableHandle };
 import { ItemComponentStatsTableHandle } from "./item_component_stats_table.ts";
 export { ItemComponentStatsTableHandle };
+import { JourneyTableHandle } from "./journey_table.ts";
+export { JourneyTableHandle };
+import { LevelThresholdTableHandle } from "./level_threshold_table.ts";
+export { LevelThresholdTableHandle };
+import { LobbyTableHandle } from "./lobby_table.ts";
+export { LobbyTableHandle };
+import { LobbyMemberTableHandle } from "./lobby_member_table.ts";
+export { LobbyMemberTableHandle };
+import { LocationTableHandle } from "./location_table.ts";
+export { LocationTableHandle };
+import { PhaseTimerTableHandle } from "./phase_timer_table.ts";
+export { PhaseTimerTableHandle };
 import { PlayerTableHandle } from "./player_table.ts";
 export { PlayerTableHandle };
 import { PlayerItemTableHandle } from "./player_item_table.ts";
 export { PlayerItemTableHandle };
+import { PveCombatTableHandle } from "./pve_combat_table.ts";
+export { PveCombatTableHandle };
+import { RoundIncomeTableHandle } from "./round_income_table.ts";
+export { RoundIncomeTableHandle };
+import { SchemaVersionTableHandle } from "./schema_version_table.ts";
+export { SchemaVersionTableHandle };
 import { ShopCrewTableHandle } from "./shop_crew_table.ts";
 export { ShopCrewTableHandle };
+import { ShopOddsTableHandle } from "./shop_odds_table.ts";
+export { ShopOddsTableHandle };
 
 // Import and reexport all types
+import { Admin } from "./admin_type.ts";
+export { Admin };
 import { Battle } from "./battle_type.ts";
 export { Battle };
 import { BattleStatus } from "./battle_status_type.ts";
 export { BattleStatus };
+import { BattleTickTimer } from "./battle_tick_timer_type.ts";
+export { BattleTickTimer };
+import { BattleUnit } from "./battle_unit_type.ts";
+export { BattleUnit };
+import { ChampionPool } from "./champion_pool_type.ts";
+export { ChampionPool };
 import { CompletedItem } from "./completed_item_type.ts";
 export { CompletedItem };
 import { CompletedItemStats } from "./completed_item_stats_type.ts";
@@ -98,23 +196,76 @@ import { CrewTemplate } from "./crew_template_type.ts";
 export { CrewTemplate };
 import { CrewTrait } from "./crew_trait_type.ts";
 export { CrewTrait };
+import { DbVector2 } from "./db_vector_2_type.ts";
+export { DbVector2 };
+import { EffectiveStats } from "./effective_stats_type.ts";
+export { EffectiveStats };
+import { Enemy } from "./enemy_type.ts";
+export { Enemy };
+import { EnemySpawn } from "./enemy_spawn_type.ts";
+export { EnemySpawn };
+import { EnemyType } from "./enemy_type_type.ts";
+export { EnemyType };
+import { GameConfig } from "./game_config_type.ts";
+export { GameConfig };
+import { GamePhase } from "./game_phase_type.ts";
+export { GamePhase };
+import { GameSession } from "./game_session_type.ts";
+export { GameSession };
+import { Item } from "./item_type.ts";
+export { Item };
 import { ItemCombinationRecipe } from "./item_combination_recipe_type.ts";
 export { ItemCombinationRecipe };
 import { ItemComponent } from "./item_component_type.ts";
 export { ItemComponent };
 import { ItemComponentStats } from "./item_component_stats_type.ts";
 export { ItemComponentStats };
+import { Journey } from "./journey_type.ts";
+export { Journey };
+import { LevelThreshold } from "./level_threshold_type.ts";
+export { LevelThreshold };
+import { Lobby } from "./lobby_type.ts";
+export { Lobby };
+import { LobbyMember } from "./lobby_member_type.ts";
+export { LobbyMember };
+import { LobbyStatus } from "./lobby_status_type.ts";
+export { LobbyStatus };
+import { Location } from "./location_type.ts";
+export { Location };
+import { LocationType } from "./location_type_type.ts";
+export { LocationType };
+import { PhaseTimer } from "./phase_timer_type.ts";
+export { PhaseTimer };
 import { Player } from "./player_type.ts";
 export { Player };
 import { PlayerItem } from "./player_item_type.ts";
 export { PlayerItem };
+import { PveCombat } from "./pve_combat_type.ts";
+export { PveCombat };
+import { RoundIncome } from "./round_income_type.ts";
+export { RoundIncome };
+import { SchemaVersion } from "./schema_version_type.ts";
+export { SchemaVersion };
 import { ShipType } from "./ship_type_type.ts";
 export { ShipType };
 import { ShopCrew } from "./shop_crew_type.ts";
 export { ShopCrew };
+import { ShopOdds } from "./shop_odds_type.ts";
+export { ShopOdds };
+import { TemplateSyncPolicy } from "./template_sync_policy_type.ts";
+export { TemplateSyncPolicy };
 
 const REMOTE_MODULE = {
   tables: {
+    admin: {
+      tableName: "admin",
+      rowType: Admin.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (Admin.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
     battle: {
       tableName: "battle",
       rowType: Battle.getTypeScriptAlgebraicType(),
@@ -124,6 +275,33 @@ const REMOTE_MODULE = {
         colType: (Battle.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
       },
     },
+    battle_tick_timer: {
+      tableName: "battle_tick_timer",
+      rowType: BattleTickTimer.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: (BattleTickTimer.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    battle_unit: {
+      tableName: "battle_unit",
+      rowType: BattleUnit.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (BattleUnit.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    champion_pool: {
+      tableName: "champion_pool",
+      rowType: ChampionPool.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (ChampionPool.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
     completed_item_stats: {
       tableName: "completed_item_stats",
       rowType: CompletedItemStats.getTypeScriptAlgebraicType(),
@@ -151,6 +329,42 @@ const REMOTE_MODULE = {
         colType: (CrewTemplate.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
       },
     },
+    effective_stats: {
+      tableName: "effective_stats",
+      rowType: EffectiveStats.getTypeScriptAlgebraicType(),
+      primaryKey: "crewId",
+      primaryKeyInfo: {
+        colName: "crewId",
+        colType: (EffectiveStats.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    enemy: {
+      tableName: "enemy",
+      rowType: Enemy.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Enemy.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    game_config: {
+      tableName: "game_config",
+      rowType: GameConfig.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (GameConfig.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    game_session: {
+      tableName: "game_session",
+      rowType: GameSession.getTypeScriptAlgebraicType(),
+      primaryKey: "lobbyId",
+      primaryKeyInfo: {
+        colName: "lobbyId",
+        colType: (GameSession.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
     item_combination_recipe: {
       tableName: "item_combination_recipe",
       rowType: ItemCombinationRecipe.getTypeScriptAlgebraicType(),
@@ -169,6 +383,60 @@ const REMOTE_MODULE = {
         colType: (ItemComponentStats.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
       },
     },
+    journey: {
+      tableName: "journey",
+      rowType: Journey.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Journey.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    level_threshold: {
+      tableName: "level_threshold",
+      rowType: LevelThreshold.getTypeScriptAlgebraicType(),
+      primaryKey: "level",
+      primaryKeyInfo: {
+        colName: "level",
+        colType: (LevelThreshold.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    lobby: {
+      tableName: "lobby",
+      rowType: Lobby.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Lobby.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    lobby_member: {
+      tableName: "lobby_member",
+      rowType: LobbyMember.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (LobbyMember.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    location: {
+      tableName: "location",
+      rowType: Location.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (Location.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    phase_timer: {
+      tableName: "phase_timer",
+      rowType: PhaseTimer.getTypeScriptAlgebraicType(),
+      primaryKey: "scheduledId",
+      primaryKeyInfo: {
+        colName: "scheduledId",
+        colType: (PhaseTimer.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
     player: {
       tableName: "player",
       rowType: Player.getTypeScriptAlgebraicType(),
@@ -187,6 +455,33 @@ const REMOTE_MODULE = {
         colType: (PlayerItem.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
       },
     },
+    pve_combat: {
+      tableName: "pve_combat",
+      rowType: PveCombat.getTypeScriptAlgebraicType(),
+      primaryKey: "locationId",
+      primaryKeyInfo: {
+        colName: "locationId",
+        colType: (PveCombat.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    round_income: {
+      tableName: "round_income",
+      rowType: RoundIncome.getTypeScriptAlgebraicType(),
+      primaryKey: "identity",
+      primaryKeyInfo: {
+        colName: "identity",
+        colType: (RoundIncome.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
+    schema_version: {
+      tableName: "schema_version",
+      rowType: SchemaVersion.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+      primaryKeyInfo: {
+        colName: "id",
+        colType: (SchemaVersion.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
     shop_crew: {
       tableName: "shop_crew",
       rowType: ShopCrew.getTypeScriptAlgebraicType(),
@@ -196,16 +491,45 @@ const REMOTE_MODULE = {
         colType: (ShopCrew.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
       },
     },
+    shop_odds: {
+      tableName: "shop_odds",
+      rowType: ShopOdds.getTypeScriptAlgebraicType(),
+      primaryKey: "level",
+      primaryKeyInfo: {
+        colName: "level",
+        colType: (ShopOdds.getTypeScriptAlgebraicType() as __AlgebraicTypeVariants.Product).value.elements[0].algebraicType,
+      },
+    },
   },
   reducers: {
+    add_item_to_inventory: {
+      reducerName: "add_item_to_inventory",
+      argsType: AddItemToInventory.getTypeScriptAlgebraicType(),
+    },
+    admin_add_crew_template: {
+      reducerName: "admin_add_crew_template",
+      argsType: AdminAddCrewTemplate.getTypeScriptAlgebraicType(),
+    },
     admin_add_recipe: {
       reducerName: "admin_add_recipe",
       argsType: AdminAddRecipe.getTypeScriptAlgebraicType(),
     },
+    admin_delete_crew_template: {
+      reducerName: "admin_delete_crew_template",
+      argsType: AdminDeleteCrewTemplate.getTypeScriptAlgebraicType(),
+    },
     admin_delete_recipe: {
       reducerName: "admin_delete_recipe",
       argsType: AdminDeleteRecipe.getTypeScriptAlgebraicType(),
     },
+    admin_reimport_crew_templates: {
+      reducerName: "admin_reimport_crew_templates",
+      argsType: AdminReimportCrewTemplates.getTypeScriptAlgebraicType(),
+    },
+    admin_run_migrations: {
+      reducerName: "admin_run_migrations",
+      argsType: AdminRunMigrations.getTypeScriptAlgebraicType(),
+    },
     admin_update_completed_item_stats: {
       reducerName: "admin_update_completed_item_stats",
       argsType: AdminUpdateCompletedItemStats.getTypeScriptAlgebraicType(),
@@ -214,18 +538,42 @@ const REMOTE_MODULE = {
       reducerName: "admin_update_crew_template",
       argsType: AdminUpdateCrewTemplate.getTypeScriptAlgebraicType(),
     },
+    admin_update_game_config: {
+      reducerName: "admin_update_game_config",
+      argsType: AdminUpdateGameConfig.getTypeScriptAlgebraicType(),
+    },
     admin_update_item_component_stats: {
       reducerName: "admin_update_item_component_stats",
       argsType: AdminUpdateItemComponentStats.getTypeScriptAlgebraicType(),
     },
+    admin_update_level_threshold: {
+      reducerName: "admin_update_level_threshold",
+      argsType: AdminUpdateLevelThreshold.getTypeScriptAlgebraicType(),
+    },
     admin_update_recipe: {
       reducerName: "admin_update_recipe",
       argsType: AdminUpdateRecipe.getTypeScriptAlgebraicType(),
     },
+    admin_update_shop_odds: {
+      reducerName: "admin_update_shop_odds",
+      argsType: AdminUpdateShopOdds.getTypeScriptAlgebraicType(),
+    },
+    advance_round: {
+      reducerName: "advance_round",
+      argsType: AdvanceRound.getTypeScriptAlgebraicType(),
+    },
+    battle_tick: {
+      reducerName: "battle_tick",
+      argsType: BattleTick.getTypeScriptAlgebraicType(),
+    },
     buy_crew: {
       reducerName: "buy_crew",
       argsType: BuyCrew.getTypeScriptAlgebraicType(),
     },
+    buy_xp: {
+      reducerName: "buy_xp",
+      argsType: BuyXp.getTypeScriptAlgebraicType(),
+    },
     client_connected: {
       reducerName: "client_connected",
       argsType: ClientConnected.getTypeScriptAlgebraicType(),
@@ -234,14 +582,46 @@ const REMOTE_MODULE = {
       reducerName: "client_disconnected",
       argsType: ClientDisconnected.getTypeScriptAlgebraicType(),
     },
+    complete_battle: {
+      reducerName: "complete_battle",
+      argsType: CompleteBattle.getTypeScriptAlgebraicType(),
+    },
+    create_lobby: {
+      reducerName: "create_lobby",
+      argsType: CreateLobby.getTypeScriptAlgebraicType(),
+    },
     equip_item_to_crew: {
       reducerName: "equip_item_to_crew",
       argsType: EquipItemToCrew.getTypeScriptAlgebraicType(),
     },
+    grant_admin: {
+      reducerName: "grant_admin",
+      argsType: GrantAdmin.getTypeScriptAlgebraicType(),
+    },
+    join_lobby: {
+      reducerName: "join_lobby",
+      argsType: JoinLobby.getTypeScriptAlgebraicType(),
+    },
+    leave_lobby: {
+      reducerName: "leave_lobby",
+      argsType: LeaveLobby.getTypeScriptAlgebraicType(),
+    },
     move_crew: {
       reducerName: "move_crew",
       argsType: MoveCrew.getTypeScriptAlgebraicType(),
     },
+    move_to_bench: {
+      reducerName: "move_to_bench",
+      argsType: MoveToBench.getTypeScriptAlgebraicType(),
+    },
+    move_to_field: {
+      reducerName: "move_to_field",
+      argsType: MoveToField.getTypeScriptAlgebraicType(),
+    },
+    phase_deadline: {
+      reducerName: "phase_deadline",
+      argsType: PhaseDeadline.getTypeScriptAlgebraicType(),
+    },
     refresh_shop: {
       reducerName: "refresh_shop",
       argsType: RefreshShop.getTypeScriptAlgebraicType(),
@@ -254,10 +634,42 @@ const REMOTE_MODULE = {
       reducerName: "remove_item_from_crew",
       argsType: RemoveItemFromCrew.getTypeScriptAlgebraicType(),
     },
+    revoke_admin: {
+      reducerName: "revoke_admin",
+      argsType: RevokeAdmin.getTypeScriptAlgebraicType(),
+    },
+    sell_crew: {
+      reducerName: "sell_crew",
+      argsType: SellCrew.getTypeScriptAlgebraicType(),
+    },
+    set_item_bench_slot: {
+      reducerName: "set_item_bench_slot",
+      argsType: SetItemBenchSlot.getTypeScriptAlgebraicType(),
+    },
+    star_up_crew: {
+      reducerName: "star_up_crew",
+      argsType: StarUpCrew.getTypeScriptAlgebraicType(),
+    },
     start_battle: {
       reducerName: "start_battle",
       argsType: StartBattle.getTypeScriptAlgebraicType(),
     },
+    start_lobby: {
+      reducerName: "start_lobby",
+      argsType: StartLobby.getTypeScriptAlgebraicType(),
+    },
+    swap_crew: {
+      reducerName: "swap_crew",
+      argsType: SwapCrew.getTypeScriptAlgebraicType(),
+    },
+    toggle_shop_lock: {
+      reducerName: "toggle_shop_lock",
+      argsType: ToggleShopLock.getTypeScriptAlgebraicType(),
+    },
+    update_ship: {
+      reducerName: "update_ship",
+      argsType: UpdateShip.getTypeScriptAlgebraicType(),
+    },
   },
   versionInfo: {
     cliVersion: "1.5.0",
@@ -288,26 +700,85 @@ const REMOTE_MODULE = {
 
 // A type representing all the possible variants of a reducer.
 export type Reducer = never
+| { name: "AddItemToInventory", args: AddItemToInventory }
+| { name: "AdminAddCrewTemplate", args: AdminAddCrewTemplate }
 | { name: "AdminAddRecipe", args: AdminAddRecipe }
+| { name: "AdminDeleteCrewTemplate", args: AdminDeleteCrewTemplate }
 | { name: "AdminDeleteRecipe", args: AdminDeleteRecipe }
+| { name: "AdminReimportCrewTemplates", args: AdminReimportCrewTemplates }
+| { name: "AdminRunMigrations", args: AdminRunMigrations }
 | { name: "AdminUpdateCompletedItemStats", args: AdminUpdateCompletedItemStats }
 | { name: "AdminUpdateCrewTemplate", args: AdminUpdateCrewTemplate }
+| { name: "AdminUpdateGameConfig", args: AdminUpdateGameConfig }
 | { name: "AdminUpdateItemComponentStats", args: AdminUpdateItemComponentStats }
+| { name: "AdminUpdateLevelThreshold", args: AdminUpdateLevelThreshold }
 | { name: "AdminUpdateRecipe", args: AdminUpdateRecipe }
+| { name: "AdminUpdateShopOdds", args: AdminUpdateShopOdds }
+| { name: "AdvanceRound", args: AdvanceRound }
+| { name: "BattleTick", args: BattleTick }
 | { name: "BuyCrew", args: BuyCrew }
+| { name: "BuyXp", args: BuyXp }
 | { name: "ClientConnected", args: ClientConnected }
 | { name: "ClientDisconnected", args: ClientDisconnected }
+| { name: "CompleteBattle", args: CompleteBattle }
+| { name: "CreateLobby", args: CreateLobby }
 | { name: "EquipItemToCrew", args: EquipItemToCrew }
+| { name: "GrantAdmin", args: GrantAdmin }
+| { name: "JoinLobby", args: JoinLobby }
+| { name: "LeaveLobby", args: LeaveLobby }
 | { name: "MoveCrew", args: MoveCrew }
+| { name: "MoveToBench", args: MoveToBench }
+| { name: "MoveToField", args: MoveToField }
+| { name: "PhaseDeadline", args: PhaseDeadline }
 | { name: "RefreshShop", args: RefreshShop }
 | { name: "RegisterPlayer", args: RegisterPlayer }
 | { name: "RemoveItemFromCrew", args: RemoveItemFromCrew }
+| { name: "RevokeAdmin", args: RevokeAdmin }
+| { name: "SellCrew", args: SellCrew }
+| { name: "SetItemBenchSlot", args: SetItemBenchSlot }
+| { name: "StarUpCrew", args: StarUpCrew }
 | { name: "StartBattle", args: StartBattle }
+| { name: "StartLobby", args: StartLobby }
+| { name: "SwapCrew", args: SwapCrew }
+| { name: "ToggleShopLock", args: ToggleShopLock }
+| { name: "UpdateShip", args: UpdateShip }
 ;
 
 export class RemoteReducers {
   constructor(private connection: __DbConnectionImpl, private setCallReducerFlags: SetReducerFlags) {}
 
+  addItemToInventory(item: Item) {
+    const __args = { item };
+    let __writer = new __BinaryWriter(1024);
+    AddItemToInventory.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_item_to_inventory", __argsBuffer, this.setCallReducerFlags.addItemToInventoryFlags);
+  }
+
+  onAddItemToInventory(callback: (ctx: ReducerEventContext, item: Item) => void) {
+    this.connection.onReducer("add_item_to_inventory", callback);
+  }
+
+  removeOnAddItemToInventory(callback: (ctx: ReducerEventContext, item: Item) => void) {
+    this.connection.offReducer("add_item_to_inventory", callback);
+  }
+
+  adminAddCrewTemplate(name: string, rarity: CrewRarity, traits: CrewTrait[], maxHp: number, abilityPower: number, attack: number, attackSpeed: number, defense: number, magicResistance: number, cost: number) {
+    const __args = { name, rarity, traits, maxHp, abilityPower, attack, attackSpeed, defense, magicResistance, cost };
+    let __writer = new __BinaryWriter(1024);
+    AdminAddCrewTemplate.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("admin_add_crew_template", __argsBuffer, this.setCallReducerFlags.adminAddCrewTemplateFlags);
+  }
+
+  onAdminAddCrewTemplate(callback: (ctx: ReducerEventContext, name: string, rarity: CrewRarity, traits: CrewTrait[], maxHp: number, abilityPower: number, attack: number, attackSpeed: number, defense: number, magicResistance: number, cost: number) => void) {
+    this.connection.onReducer("admin_add_crew_template", callback);
+  }
+
+  removeOnAdminAddCrewTemplate(callback: (ctx: ReducerEventContext, name: string, rarity: CrewRarity, traits: CrewTrait[], maxHp: number, abilityPower: number, attack: number, attackSpeed: number, defense: number, magicResistance: number, cost: number) => void) {
+    this.connection.offReducer("admin_add_crew_template", callback);
+  }
+
   adminAddRecipe(component1: ItemComponent, component2: ItemComponent, result: CompletedItem) {
     const __args = { component1, component2, result };
     let __writer = new __BinaryWriter(1024);
@@ -324,6 +795,22 @@ export class RemoteReducers {
     this.connection.offReducer("admin_add_recipe", callback);
   }
 
+  adminDeleteCrewTemplate(templateId: bigint) {
+    const __args = { templateId };
+    let __writer = new __BinaryWriter(1024);
+    AdminDeleteCrewTemplate.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("admin_delete_crew_template", __argsBuffer, this.setCallReducerFlags.adminDeleteCrewTemplateFlags);
+  }
+
+  onAdminDeleteCrewTemplate(callback: (ctx: ReducerEventContext, templateId: bigint) => void) {
+    this.connection.onReducer("admin_delete_crew_template", callback);
+  }
+
+  removeOnAdminDeleteCrewTemplate(callback: (ctx: ReducerEventContext, templateId: bigint) => void) {
+    this.connection.offReducer("admin_delete_crew_template", callback);
+  }
+
   adminDeleteRecipe(recipeId: bigint) {
     const __args = { recipeId };
     let __writer = new __BinaryWriter(1024);
@@ -340,38 +827,82 @@ export class RemoteReducers {
     this.connection.offReducer("admin_delete_recipe", callback);
   }
 
-  adminUpdateCompletedItemStats(item: CompletedItem, name: string | undefined, description: string | undefined, bonusAd: number | undefined, bonusCritChance: number | undefined, bonusCritDamage: number | undefined, bonusAttackSpeed: number | undefined, bonusAp: number | undefined, bonusArmor: number | undefined, bonusMr: number | undefined, bonusMana: number | undefined, bonusHp: number | undefined, bonusHpRegen: number | undefined, hasSplash: boolean | undefined, armorShred: number | undefined) {
-    const __args = { item, name, description, bonusAd, bonusCritChance, bonusCritDamage, bonusAttackSpeed, bonusAp, bonusArmor, bonusMr, bonusMana, bonusHp, bonusHpRegen, hasSplash, armorShred };
+  adminReimportCrewTemplates(syncPolicy: TemplateSyncPolicy) {
+    const __args = { syncPolicy };
+    let __writer = new __BinaryWriter(1024);
+    AdminReimportCrewTemplates.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("admin_reimport_crew_templates", __argsBuffer, this.setCallReducerFlags.adminReimportCrewTemplatesFlags);
+  }
+
+  onAdminReimportCrewTemplates(callback: (ctx: ReducerEventContext, syncPolicy: TemplateSyncPolicy) => void) {
+    this.connection.onReducer("admin_reimport_crew_templates", callback);
+  }
+
+  removeOnAdminReimportCrewTemplates(callback: (ctx: ReducerEventContext, syncPolicy: TemplateSyncPolicy) => void) {
+    this.connection.offReducer("admin_reimport_crew_templates", callback);
+  }
+
+  adminRunMigrations() {
+    this.connection.callReducer("admin_run_migrations", new Uint8Array(0), this.setCallReducerFlags.adminRunMigrationsFlags);
+  }
+
+  onAdminRunMigrations(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("admin_run_migrations", callback);
+  }
+
+  removeOnAdminRunMigrations(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("admin_run_migrations", callback);
+  }
+
+  adminUpdateCompletedItemStats(item: CompletedItem, name: string | undefined, description: string | undefined, bonusAd: number | undefined, bonusCritChance: number | undefined, bonusCritDamage: number | undefined, bonusAttackSpeed: number | undefined, bonusAp: number | undefined, bonusArmor: number | undefined, bonusMr: number | undefined, bonusMana: number | undefined, bonusHp: number | undefined, bonusHpRegen: number | undefined, hasSplash: boolean | undefined, armorShred: number | undefined, procEvery: number | undefined, procChance: number | undefined, procDamage: number | undefined, procMultiplier: number | undefined, procShots: number | undefined, procStunSeconds: number | undefined) {
+    const __args = { item, name, description, bonusAd, bonusCritChance, bonusCritDamage, bonusAttackSpeed, bonusAp, bonusArmor, bonusMr, bonusMana, bonusHp, bonusHpRegen, hasSplash, armorShred, procEvery, procChance, procDamage, procMultiplier, procShots, procStunSeconds };
     let __writer = new __BinaryWriter(1024);
     AdminUpdateCompletedItemStats.serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
     this.connection.callReducer("admin_update_completed_item_stats", __argsBuffer, this.setCallReducerFlags.adminUpdateCompletedItemStatsFlags);
   }
 
-  onAdminUpdateCompletedItemStats(callback: (ctx: ReducerEventContext, item: CompletedItem, name: string | undefined, description: string | undefined, bonusAd: number | undefined, bonusCritChance: number | undefined, bonusCritDamage: number | undefined, bonusAttackSpeed: number | undefined, bonusAp: number | undefined, bonusArmor: number | undefined, bonusMr: number | undefined, bonusMana: number | undefined, bonusHp: number | undefined, bonusHpRegen: number | undefined, hasSplash: boolean | undefined, armorShred: number | undefined) => void) {
+  onAdminUpdateCompletedItemStats(callback: (ctx: ReducerEventContext, item: CompletedItem, name: string | undefined, description: string | undefined, bonusAd: number | undefined, bonusCritChance: number | undefined, bonusCritDamage: number | undefined, bonusAttackSpeed: number | undefined, bonusAp: number | undefined, bonusArmor: number | undefined, bonusMr: number | undefined, bonusMana: number | undefined, bonusHp: number | undefined, bonusHpRegen: number | undefined, hasSplash: boolean | undefined, armorShred: number | undefined, procEvery: number | undefined, procChance: number | undefined, procDamage: number | undefined, procMultiplier: number | undefined, procShots: number | undefined, procStunSeconds: number | undefined) => void) {
     this.connection.onReducer("admin_update_completed_item_stats", callback);
   }
 
-  removeOnAdminUpdateCompletedItemStats(callback: (ctx: ReducerEventContext, item: CompletedItem, name: string | undefined, description: string | undefined, bonusAd: number | undefined, bonusCritChance: number | undefined, bonusCritDamage: number | undefined, bonusAttackSpeed: number | undefined, bonusAp: number | undefined, bonusArmor: number | undefined, bonusMr: number | undefined, bonusMana: number | undefined, bonusHp: number | undefined, bonusHpRegen: number | undefined, hasSplash: boolean | undefined, armorShred: number | undefined) => void) {
+  removeOnAdminUpdateCompletedItemStats(callback: (ctx: ReducerEventContext, item: CompletedItem, name: string | undefined, description: string | undefined, bonusAd: number | undefined, bonusCritChance: number | undefined, bonusCritDamage: number | undefined, bonusAttackSpeed: number | undefined, bonusAp: number | undefined, bonusArmor: number | undefined, bonusMr: number | undefined, bonusMana: number | undefined, bonusHp: number | undefined, bonusHpRegen: number | undefined, hasSplash: boolean | undefined, armorShred: number | undefined, procEvery: number | undefined, procChance: number | undefined, procDamage: number | undefined, procMultiplier: number | undefined, procShots: number | undefined, procStunSeconds: number | undefined) => void) {
     this.connection.offReducer("admin_update_completed_item_stats", callback);
   }
 
-  adminUpdateCrewTemplate(templateId: bigint, name: string | undefined, maxHp: number | undefined, attack: number | undefined, defense: number | undefined, cost: number | undefined) {
-    const __args = { templateId, name, maxHp, attack, defense, cost };
+  adminUpdateCrewTemplate(templateId: bigint, name: string | undefined, maxHp: number | undefined, attack: number | undefined, defense: number | undefined, cost: number | undefined, rarity: CrewRarity | undefined, traits: CrewTrait[] | undefined, abilityPower: number | undefined, attackSpeed: number | undefined, magicResistance: number | undefined, syncPolicy: TemplateSyncPolicy) {
+    const __args = { templateId, name, maxHp, attack, defense, cost, rarity, traits, abilityPower, attackSpeed, magicResistance, syncPolicy };
     let __writer = new __BinaryWriter(1024);
     AdminUpdateCrewTemplate.serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
     this.connection.callReducer("admin_update_crew_template", __argsBuffer, this.setCallReducerFlags.adminUpdateCrewTemplateFlags);
   }
 
-  onAdminUpdateCrewTemplate(callback: (ctx: ReducerEventContext, templateId: bigint, name: string | undefined, maxHp: number | undefined, attack: number | undefined, defense: number | undefined, cost: number | undefined) => void) {
+  onAdminUpdateCrewTemplate(callback: (ctx: ReducerEventContext, templateId: bigint, name: string | undefined, maxHp: number | undefined, attack: number | undefined, defense: number | undefined, cost: number | undefined, rarity: CrewRarity | undefined, traits: CrewTrait[] | undefined, abilityPower: number | undefined, attackSpeed: number | undefined, magicResistance: number | undefined, syncPolicy: TemplateSyncPolicy) => void) {
     this.connection.onReducer("admin_update_crew_template", callback);
   }
 
-  removeOnAdminUpdateCrewTemplate(callback: (ctx: ReducerEventContext, templateId: bigint, name: string | undefined, maxHp: number | undefined, attack: number | undefined, defense: number | undefined, cost: number | undefined) => void) {
+  removeOnAdminUpdateCrewTemplate(callback: (ctx: ReducerEventContext, templateId: bigint, name: string | undefined, maxHp: number | undefined, attack: number | undefined, defense: number | undefined, cost: number | undefined, rarity: CrewRarity | undefined, traits: CrewTrait[] | undefined, abilityPower: number | undefined, attackSpeed: number | undefined, magicResistance: number | undefined, syncPolicy: TemplateSyncPolicy) => void) {
     this.connection.offReducer("admin_update_crew_template", callback);
   }
 
+  adminUpdateGameConfig(rerollCost: number | undefined) {
+    const __args = { rerollCost };
+    let __writer = new __BinaryWriter(1024);
+    AdminUpdateGameConfig.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("admin_update_game_config", __argsBuffer, this.setCallReducerFlags.adminUpdateGameConfigFlags);
+  }
+
+  onAdminUpdateGameConfig(callback: (ctx: ReducerEventContext, rerollCost: number | undefined) => void) {
+    this.connection.onReducer("admin_update_game_config", callback);
+  }
+
+  removeOnAdminUpdateGameConfig(callback: (ctx: ReducerEventContext, rerollCost: number | undefined) => void) {
+    this.connection.offReducer("admin_update_game_config", callback);
+  }
+
   adminUpdateItemComponentStats(component: ItemComponent, name: string | undefined, description: string | undefined, bonusAd: number | undefined, bonusCritChance: number | undefined, bonusAttackSpeed: number | undefined, bonusAp: number | undefined, bonusArmor: number | undefined, bonusMr: number | undefined, bonusMana: number | undefined, bonusHp: number | undefined) {
     const __args = { component, name, description, bonusAd, bonusCritChance, bonusAttackSpeed, bonusAp, bonusArmor, bonusMr, bonusMana, bonusHp };
     let __writer = new __BinaryWriter(1024);
@@ -388,6 +919,22 @@ export class RemoteReducers {
     this.connection.offReducer("admin_update_item_component_stats", callback);
   }
 
+  adminUpdateLevelThreshold(level: number, xpRequired: number) {
+    const __args = { level, xpRequired };
+    let __writer = new __BinaryWriter(1024);
+    AdminUpdateLevelThreshold.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("admin_update_level_threshold", __argsBuffer, this.setCallReducerFlags.adminUpdateLevelThresholdFlags);
+  }
+
+  onAdminUpdateLevelThreshold(callback: (ctx: ReducerEventContext, level: number, xpRequired: number) => void) {
+    this.connection.onReducer("admin_update_level_threshold", callback);
+  }
+
+  removeOnAdminUpdateLevelThreshold(callback: (ctx: ReducerEventContext, level: number, xpRequired: number) => void) {
+    this.connection.offReducer("admin_update_level_threshold", callback);
+  }
+
   adminUpdateRecipe(recipeId: bigint, component1: ItemComponent | undefined, component2: ItemComponent | undefined, result: CompletedItem | undefined) {
     const __args = { recipeId, component1, component2, result };
     let __writer = new __BinaryWriter(1024);
@@ -404,6 +951,50 @@ export class RemoteReducers {
     this.connection.offReducer("admin_update_recipe", callback);
   }
 
+  adminUpdateShopOdds(level: number, common: number, uncommon: number, rare: number, epic: number, legendary: number) {
+    const __args = { level, common, uncommon, rare, epic, legendary };
+    let __writer = new __BinaryWriter(1024);
+    AdminUpdateShopOdds.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("admin_update_shop_odds", __argsBuffer, this.setCallReducerFlags.adminUpdateShopOddsFlags);
+  }
+
+  onAdminUpdateShopOdds(callback: (ctx: ReducerEventContext, level: number, common: number, uncommon: number, rare: number, epic: number, legendary: number) => void) {
+    this.connection.onReducer("admin_update_shop_odds", callback);
+  }
+
+  removeOnAdminUpdateShopOdds(callback: (ctx: ReducerEventContext, level: number, common: number, uncommon: number, rare: number, epic: number, legendary: number) => void) {
+    this.connection.offReducer("admin_update_shop_odds", callback);
+  }
+
+  advanceRound() {
+    this.connection.callReducer("advance_round", new Uint8Array(0), this.setCallReducerFlags.advanceRoundFlags);
+  }
+
+  onAdvanceRound(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("advance_round", callback);
+  }
+
+  removeOnAdvanceRound(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("advance_round", callback);
+  }
+
+  battleTick(timer: BattleTickTimer) {
+    const __args = { timer };
+    let __writer = new __BinaryWriter(1024);
+    BattleTick.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("battle_tick", __argsBuffer, this.setCallReducerFlags.battleTickFlags);
+  }
+
+  onBattleTick(callback: (ctx: ReducerEventContext, timer: BattleTickTimer) => void) {
+    this.connection.onReducer("battle_tick", callback);
+  }
+
+  removeOnBattleTick(callback: (ctx: ReducerEventContext, timer: BattleTickTimer) => void) {
+    this.connection.offReducer("battle_tick", callback);
+  }
+
   buyCrew(shopCrewId: bigint, slotIndex: number | undefined) {
     const __args = { shopCrewId, slotIndex };
     let __writer = new __BinaryWriter(1024);
@@ -420,6 +1011,18 @@ export class RemoteReducers {
     this.connection.offReducer("buy_crew", callback);
   }
 
+  buyXp() {
+    this.connection.callReducer("buy_xp", new Uint8Array(0), this.setCallReducerFlags.buyXpFlags);
+  }
+
+  onBuyXp(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("buy_xp", callback);
+  }
+
+  removeOnBuyXp(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("buy_xp", callback);
+  }
+
   onClientConnected(callback: (ctx: ReducerEventContext) => void) {
     this.connection.onReducer("client_connected", callback);
   }
@@ -436,22 +1039,98 @@ export class RemoteReducers {
     this.connection.offReducer("client_disconnected", callback);
   }
 
-  equipItemToCrew(crewId: bigint, component: ItemComponent) {
-    const __args = { crewId, component };
+  completeBattle(battleId: bigint) {
+    const __args = { battleId };
+    let __writer = new __BinaryWriter(1024);
+    CompleteBattle.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("complete_battle", __argsBuffer, this.setCallReducerFlags.completeBattleFlags);
+  }
+
+  onCompleteBattle(callback: (ctx: ReducerEventContext, battleId: bigint) => void) {
+    this.connection.onReducer("complete_battle", callback);
+  }
+
+  removeOnCompleteBattle(callback: (ctx: ReducerEventContext, battleId: bigint) => void) {
+    this.connection.offReducer("complete_battle", callback);
+  }
+
+  createLobby(name: string) {
+    const __args = { name };
+    let __writer = new __BinaryWriter(1024);
+    CreateLobby.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_lobby", __argsBuffer, this.setCallReducerFlags.createLobbyFlags);
+  }
+
+  onCreateLobby(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.onReducer("create_lobby", callback);
+  }
+
+  removeOnCreateLobby(callback: (ctx: ReducerEventContext, name: string) => void) {
+    this.connection.offReducer("create_lobby", callback);
+  }
+
+  equipItemToCrew(crewId: bigint, playerItemId: bigint) {
+    const __args = { crewId, playerItemId };
     let __writer = new __BinaryWriter(1024);
     EquipItemToCrew.serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
     this.connection.callReducer("equip_item_to_crew", __argsBuffer, this.setCallReducerFlags.equipItemToCrewFlags);
   }
 
-  onEquipItemToCrew(callback: (ctx: ReducerEventContext, crewId: bigint, component: ItemComponent) => void) {
+  onEquipItemToCrew(callback: (ctx: ReducerEventContext, crewId: bigint, playerItemId: bigint) => void) {
     this.connection.onReducer("equip_item_to_crew", callback);
   }
 
-  removeOnEquipItemToCrew(callback: (ctx: ReducerEventContext, crewId: bigint, component: ItemComponent) => void) {
+  removeOnEquipItemToCrew(callback: (ctx: ReducerEventContext, crewId: bigint, playerItemId: bigint) => void) {
     this.connection.offReducer("equip_item_to_crew", callback);
   }
 
+  grantAdmin(identity: __Identity) {
+    const __args = { identity };
+    let __writer = new __BinaryWriter(1024);
+    GrantAdmin.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("grant_admin", __argsBuffer, this.setCallReducerFlags.grantAdminFlags);
+  }
+
+  onGrantAdmin(callback: (ctx: ReducerEventContext, identity: __Identity) => void) {
+    this.connection.onReducer("grant_admin", callback);
+  }
+
+  removeOnGrantAdmin(callback: (ctx: ReducerEventContext, identity: __Identity) => void) {
+    this.connection.offReducer("grant_admin", callback);
+  }
+
+  joinLobby(lobbyId: bigint) {
+    const __args = { lobbyId };
+    let __writer = new __BinaryWriter(1024);
+    JoinLobby.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("join_lobby", __argsBuffer, this.setCallReducerFlags.joinLobbyFlags);
+  }
+
+  onJoinLobby(callback: (ctx: ReducerEventContext, lobbyId: bigint) => void) {
+    this.connection.onReducer("join_lobby", callback);
+  }
+
+  removeOnJoinLobby(callback: (ctx: ReducerEventContext, lobbyId: bigint) => void) {
+    this.connection.offReducer("join_lobby", callback);
+  }
+
+  leaveLobby() {
+    this.connection.callReducer("leave_lobby", new Uint8Array(0), this.setCallReducerFlags.leaveLobbyFlags);
+  }
+
+  onLeaveLobby(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("leave_lobby", callback);
+  }
+
+  removeOnLeaveLobby(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("leave_lobby", callback);
+  }
+
   moveCrew(crewId: bigint, newSlot: number | undefined) {
     const __args = { crewId, newSlot };
     let __writer = new __BinaryWriter(1024);
@@ -468,6 +1147,54 @@ export class RemoteReducers {
     this.connection.offReducer("move_crew", callback);
   }
 
+  moveToBench(crewId: bigint, benchIndex: number | undefined) {
+    const __args = { crewId, benchIndex };
+    let __writer = new __BinaryWriter(1024);
+    MoveToBench.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("move_to_bench", __argsBuffer, this.setCallReducerFlags.moveToBenchFlags);
+  }
+
+  onMoveToBench(callback: (ctx: ReducerEventContext, crewId: bigint, benchIndex: number | undefined) => void) {
+    this.connection.onReducer("move_to_bench", callback);
+  }
+
+  removeOnMoveToBench(callback: (ctx: ReducerEventContext, crewId: bigint, benchIndex: number | undefined) => void) {
+    this.connection.offReducer("move_to_bench", callback);
+  }
+
+  moveToField(crewId: bigint, slotIndex: number) {
+    const __args = { crewId, slotIndex };
+    let __writer = new __BinaryWriter(1024);
+    MoveToField.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("move_to_field", __argsBuffer, this.setCallReducerFlags.moveToFieldFlags);
+  }
+
+  onMoveToField(callback: (ctx: ReducerEventContext, crewId: bigint, slotIndex: number) => void) {
+    this.connection.onReducer("move_to_field", callback);
+  }
+
+  removeOnMoveToField(callback: (ctx: ReducerEventContext, crewId: bigint, slotIndex: number) => void) {
+    this.connection.offReducer("move_to_field", callback);
+  }
+
+  phaseDeadline(timer: PhaseTimer) {
+    const __args = { timer };
+    let __writer = new __BinaryWriter(1024);
+    PhaseDeadline.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("phase_deadline", __argsBuffer, this.setCallReducerFlags.phaseDeadlineFlags);
+  }
+
+  onPhaseDeadline(callback: (ctx: ReducerEventContext, timer: PhaseTimer) => void) {
+    this.connection.onReducer("phase_deadline", callback);
+  }
+
+  removeOnPhaseDeadline(callback: (ctx: ReducerEventContext, timer: PhaseTimer) => void) {
+    this.connection.offReducer("phase_deadline", callback);
+  }
+
   refreshShop() {
     this.connection.callReducer("refresh_shop", new Uint8Array(0), this.setCallReducerFlags.refreshShopFlags);
   }
@@ -512,6 +1239,66 @@ export class RemoteReducers {
     this.connection.offReducer("remove_item_from_crew", callback);
   }
 
+  revokeAdmin(identity: __Identity) {
+    const __args = { identity };
+    let __writer = new __BinaryWriter(1024);
+    RevokeAdmin.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("revoke_admin", __argsBuffer, this.setCallReducerFlags.revokeAdminFlags);
+  }
+
+  onRevokeAdmin(callback: (ctx: ReducerEventContext, identity: __Identity) => void) {
+    this.connection.onReducer("revoke_admin", callback);
+  }
+
+  removeOnRevokeAdmin(callback: (ctx: ReducerEventContext, identity: __Identity) => void) {
+    this.connection.offReducer("revoke_admin", callback);
+  }
+
+  sellCrew(crewId: bigint) {
+    const __args = { crewId };
+    let __writer = new __BinaryWriter(1024);
+    SellCrew.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("sell_crew", __argsBuffer, this.setCallReducerFlags.sellCrewFlags);
+  }
+
+  onSellCrew(callback: (ctx: ReducerEventContext, crewId: bigint) => void) {
+    this.connection.onReducer("sell_crew", callback);
+  }
+
+  removeOnSellCrew(callback: (ctx: ReducerEventContext, crewId: bigint) => void) {
+    this.connection.offReducer("sell_crew", callback);
+  }
+
+  setItemBenchSlot(playerItemId: bigint, benchSlot: number | undefined) {
+    const __args = { playerItemId, benchSlot };
+    let __writer = new __BinaryWriter(1024);
+    SetItemBenchSlot.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_item_bench_slot", __argsBuffer, this.setCallReducerFlags.setItemBenchSlotFlags);
+  }
+
+  onSetItemBenchSlot(callback: (ctx: ReducerEventContext, playerItemId: bigint, benchSlot: number | undefined) => void) {
+    this.connection.onReducer("set_item_bench_slot", callback);
+  }
+
+  removeOnSetItemBenchSlot(callback: (ctx: ReducerEventContext, playerItemId: bigint, benchSlot: number | undefined) => void) {
+    this.connection.offReducer("set_item_bench_slot", callback);
+  }
+
+  starUpCrew() {
+    this.connection.callReducer("star_up_crew", new Uint8Array(0), this.setCallReducerFlags.starUpCrewFlags);
+  }
+
+  onStarUpCrew(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("star_up_crew", callback);
+  }
+
+  removeOnStarUpCrew(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("star_up_crew", callback);
+  }
+
   startBattle() {
     this.connection.callReducer("start_battle", new Uint8Array(0), this.setCallReducerFlags.startBattleFlags);
   }
@@ -524,19 +1311,96 @@ export class RemoteReducers {
     this.connection.offReducer("start_battle", callback);
   }
 
+  startLobby() {
+    this.connection.callReducer("start_lobby", new Uint8Array(0), this.setCallReducerFlags.startLobbyFlags);
+  }
+
+  onStartLobby(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("start_lobby", callback);
+  }
+
+  removeOnStartLobby(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("start_lobby", callback);
+  }
+
+  swapCrew(crewId: bigint, otherCrewId: bigint) {
+    const __args = { crewId, otherCrewId };
+    let __writer = new __BinaryWriter(1024);
+    SwapCrew.serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("swap_crew", __argsBuffer, this.setCallReducerFlags.swapCrewFlags);
+  }
+
+  onSwapCrew(callback: (ctx: ReducerEventContext, crewId: bigint, otherCrewId: bigint) => void) {
+    this.connection.onReducer("swap_crew", callback);
+  }
+
+  removeOnSwapCrew(callback: (ctx: ReducerEventContext, crewId: bigint, otherCrewId: bigint) => void) {
+    this.connection.offReducer("swap_crew", callback);
+  }
+
+  toggleShopLock() {
+    this.connection.callReducer("toggle_shop_lock", new Uint8Array(0), this.setCallReducerFlags.toggleShopLockFlags);
+  }
+
+  onToggleShopLock(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("toggle_shop_lock", callback);
+  }
+
+  removeOnToggleShopLock(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("toggle_shop_lock", callback);
+  }
+
+  updateShip() {
+    this.connection.callReducer("update_ship", new Uint8Array(0), this.setCallReducerFlags.updateShipFlags);
+  }
+
+  onUpdateShip(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("update_ship", callback);
+  }
+
+  removeOnUpdateShip(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("update_ship", callback);
+  }
+
 }
 
 export class SetReducerFlags {
+  addItemToInventoryFlags: __CallReducerFlags = 'FullUpdate';
+  addItemToInventory(flags: __CallReducerFlags) {
+    this.addItemToInventoryFlags = flags;
+  }
+
+  adminAddCrewTemplateFlags: __CallReducerFlags = 'FullUpdate';
+  adminAddCrewTemplate(flags: __CallReducerFlags) {
+    this.adminAddCrewTemplateFlags = flags;
+  }
+
   adminAddRecipeFlags: __CallReducerFlags = 'FullUpdate';
   adminAddRecipe(flags: __CallReducerFlags) {
     this.adminAddRecipeFlags = flags;
   }
 
+  adminDeleteCrewTemplateFlags: __CallReducerFlags = 'FullUpdate';
+  adminDeleteCrewTemplate(flags: __CallReducerFlags) {
+    this.adminDeleteCrewTemplateFlags = flags;
+  }
+
   adminDeleteRecipeFlags: __CallReducerFlags = 'FullUpdate';
   adminDeleteRecipe(flags: __CallReducerFlags) {
     this.adminDeleteRecipeFlags = flags;
   }
 
+  adminReimportCrewTemplatesFlags: __CallReducerFlags = 'FullUpdate';
+  adminReimportCrewTemplates(flags: __CallReducerFlags) {
+    this.adminReimportCrewTemplatesFlags = flags;
+  }
+
+  adminRunMigrationsFlags: __CallReducerFlags = 'FullUpdate';
+  adminRunMigrations(flags: __CallReducerFlags) {
+    this.adminRunMigrationsFlags = flags;
+  }
+
   adminUpdateCompletedItemStatsFlags: __CallReducerFlags = 'FullUpdate';
   adminUpdateCompletedItemStats(flags: __CallReducerFlags) {
     this.adminUpdateCompletedItemStatsFlags = flags;
@@ -547,31 +1411,101 @@ export class SetReducerFlags {
     this.adminUpdateCrewTemplateFlags = flags;
   }
 
+  adminUpdateGameConfigFlags: __CallReducerFlags = 'FullUpdate';
+  adminUpdateGameConfig(flags: __CallReducerFlags) {
+    this.adminUpdateGameConfigFlags = flags;
+  }
+
   adminUpdateItemComponentStatsFlags: __CallReducerFlags = 'FullUpdate';
   adminUpdateItemComponentStats(flags: __CallReducerFlags) {
     this.adminUpdateItemComponentStatsFlags = flags;
   }
 
+  adminUpdateLevelThresholdFlags: __CallReducerFlags = 'FullUpdate';
+  adminUpdateLevelThreshold(flags: __CallReducerFlags) {
+    this.adminUpdateLevelThresholdFlags = flags;
+  }
+
   adminUpdateRecipeFlags: __CallReducerFlags = 'FullUpdate';
   adminUpdateRecipe(flags: __CallReducerFlags) {
     this.adminUpdateRecipeFlags = flags;
   }
 
+  adminUpdateShopOddsFlags: __CallReducerFlags = 'FullUpdate';
+  adminUpdateShopOdds(flags: __CallReducerFlags) {
+    this.adminUpdateShopOddsFlags = flags;
+  }
+
+  advanceRoundFlags: __CallReducerFlags = 'FullUpdate';
+  advanceRound(flags: __CallReducerFlags) {
+    this.advanceRoundFlags = flags;
+  }
+
+  battleTickFlags: __CallReducerFlags = 'FullUpdate';
+  battleTick(flags: __CallReducerFlags) {
+    this.battleTickFlags = flags;
+  }
+
   buyCrewFlags: __CallReducerFlags = 'FullUpdate';
   buyCrew(flags: __CallReducerFlags) {
     this.buyCrewFlags = flags;
   }
 
+  buyXpFlags: __CallReducerFlags = 'FullUpdate';
+  buyXp(flags: __CallReducerFlags) {
+    this.buyXpFlags = flags;
+  }
+
+  completeBattleFlags: __CallReducerFlags = 'FullUpdate';
+  completeBattle(flags: __CallReducerFlags) {
+    this.completeBattleFlags = flags;
+  }
+
+  createLobbyFlags: __CallReducerFlags = 'FullUpdate';
+  createLobby(flags: __CallReducerFlags) {
+    this.createLobbyFlags = flags;
+  }
+
   equipItemToCrewFlags: __CallReducerFlags = 'FullUpdate';
   equipItemToCrew(flags: __CallReducerFlags) {
     this.equipItemToCrewFlags = flags;
   }
 
+  grantAdminFlags: __CallReducerFlags = 'FullUpdate';
+  grantAdmin(flags: __CallReducerFlags) {
+    this.grantAdminFlags = flags;
+  }
+
+  joinLobbyFlags: __CallReducerFlags = 'FullUpdate';
+  joinLobby(flags: __CallReducerFlags) {
+    this.joinLobbyFlags = flags;
+  }
+
+  leaveLobbyFlags: __CallReducerFlags = 'FullUpdate';
+  leaveLobby(flags: __CallReducerFlags) {
+    this.leaveLobbyFlags = flags;
+  }
+
   moveCrewFlags: __CallReducerFlags = 'FullUpdate';
   moveCrew(flags: __CallReducerFlags) {
     this.moveCrewFlags = flags;
   }
 
+  moveToBenchFlags: __CallReducerFlags = 'FullUpdate';
+  moveToBench(flags: __CallReducerFlags) {
+    this.moveToBenchFlags = flags;
+  }
+
+  moveToFieldFlags: __CallReducerFlags = 'FullUpdate';
+  moveToField(flags: __CallReducerFlags) {
+    this.moveToFieldFlags = flags;
+  }
+
+  phaseDeadlineFlags: __CallReducerFlags = 'FullUpdate';
+  phaseDeadline(flags: __CallReducerFlags) {
+    this.phaseDeadlineFlags = flags;
+  }
+
   refreshShopFlags: __CallReducerFlags = 'FullUpdate';
   refreshShop(flags: __CallReducerFlags) {
     this.refreshShopFlags = flags;
@@ -587,21 +1521,81 @@ export class SetReducerFlags {
     this.removeItemFromCrewFlags = flags;
   }
 
+  revokeAdminFlags: __CallReducerFlags = 'FullUpdate';
+  revokeAdmin(flags: __CallReducerFlags) {
+    this.revokeAdminFlags = flags;
+  }
+
+  sellCrewFlags: __CallReducerFlags = 'FullUpdate';
+  sellCrew(flags: __CallReducerFlags) {
+    this.sellCrewFlags = flags;
+  }
+
+  setItemBenchSlotFlags: __CallReducerFlags = 'FullUpdate';
+  setItemBenchSlot(flags: __CallReducerFlags) {
+    this.setItemBenchSlotFlags = flags;
+  }
+
+  starUpCrewFlags: __CallReducerFlags = 'FullUpdate';
+  starUpCrew(flags: __CallReducerFlags) {
+    this.starUpCrewFlags = flags;
+  }
+
   startBattleFlags: __CallReducerFlags = 'FullUpdate';
   startBattle(flags: __CallReducerFlags) {
     this.startBattleFlags = flags;
   }
 
+  startLobbyFlags: __CallReducerFlags = 'FullUpdate';
+  startLobby(flags: __CallReducerFlags) {
+    this.startLobbyFlags = flags;
+  }
+
+  swapCrewFlags: __CallReducerFlags = 'FullUpdate';
+  swapCrew(flags: __CallReducerFlags) {
+    this.swapCrewFlags = flags;
+  }
+
+  toggleShopLockFlags: __CallReducerFlags = 'FullUpdate';
+  toggleShopLock(flags: __CallReducerFlags) {
+    this.toggleShopLockFlags = flags;
+  }
+
+  updateShipFlags: __CallReducerFlags = 'FullUpdate';
+  updateShip(flags: __CallReducerFlags) {
+    this.updateShipFlags = flags;
+  }
+
 }
 
 export class RemoteTables {
   constructor(private connection: __DbConnectionImpl) {}
 
+  get admin(): AdminTableHandle {
+    // clientCache is a private property
+    return new AdminTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Admin>(REMOTE_MODULE.tables.admin));
+  }
+
   get battle(): BattleTableHandle {
     // clientCache is a private property
     return new BattleTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Battle>(REMOTE_MODULE.tables.battle));
   }
 
+  get battleTickTimer(): BattleTickTimerTableHandle {
+    // clientCache is a private property
+    return new BattleTickTimerTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<BattleTickTimer>(REMOTE_MODULE.tables.battle_tick_timer));
+  }
+
+  get battleUnit(): BattleUnitTableHandle {
+    // clientCache is a private property
+    return new BattleUnitTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<BattleUnit>(REMOTE_MODULE.tables.battle_unit));
+  }
+
+  get championPool(): ChampionPoolTableHandle {
+    // clientCache is a private property
+    return new ChampionPoolTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<ChampionPool>(REMOTE_MODULE.tables.champion_pool));
+  }
+
   get completedItemStats(): CompletedItemStatsTableHandle {
     // clientCache is a private property
     return new CompletedItemStatsTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<CompletedItemStats>(REMOTE_MODULE.tables.completed_item_stats));
@@ -617,6 +1611,26 @@ export class RemoteTables {
     return new CrewTemplateTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<CrewTemplate>(REMOTE_MODULE.tables.crew_template));
   }
 
+  get effectiveStats(): EffectiveStatsTableHandle {
+    // clientCache is a private property
+    return new EffectiveStatsTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<EffectiveStats>(REMOTE_MODULE.tables.effective_stats));
+  }
+
+  get enemy(): EnemyTableHandle {
+    // clientCache is a private property
+    return new EnemyTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Enemy>(REMOTE_MODULE.tables.enemy));
+  }
+
+  get gameConfig(): GameConfigTableHandle {
+    // clientCache is a private property
+    return new GameConfigTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GameConfig>(REMOTE_MODULE.tables.game_config));
+  }
+
+  get gameSession(): GameSessionTableHandle {
+    // clientCache is a private property
+    return new GameSessionTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<GameSession>(REMOTE_MODULE.tables.game_session));
+  }
+
   get itemCombinationRecipe(): ItemCombinationRecipeTableHandle {
     // clientCache is a private property
     return new ItemCombinationRecipeTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<ItemCombinationRecipe>(REMOTE_MODULE.tables.item_combination_recipe));
@@ -627,6 +1641,36 @@ export class RemoteTables {
     return new ItemComponentStatsTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<ItemComponentStats>(REMOTE_MODULE.tables.item_component_stats));
   }
 
+  get journey(): JourneyTableHandle {
+    // clientCache is a private property
+    return new JourneyTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Journey>(REMOTE_MODULE.tables.journey));
+  }
+
+  get levelThreshold(): LevelThresholdTableHandle {
+    // clientCache is a private property
+    return new LevelThresholdTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<LevelThreshold>(REMOTE_MODULE.tables.level_threshold));
+  }
+
+  get lobby(): LobbyTableHandle {
+    // clientCache is a private property
+    return new LobbyTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Lobby>(REMOTE_MODULE.tables.lobby));
+  }
+
+  get lobbyMember(): LobbyMemberTableHandle {
+    // clientCache is a private property
+    return new LobbyMemberTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<LobbyMember>(REMOTE_MODULE.tables.lobby_member));
+  }
+
+  get location(): LocationTableHandle {
+    // clientCache is a private property
+    return new LocationTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Location>(REMOTE_MODULE.tables.location));
+  }
+
+  get phaseTimer(): PhaseTimerTableHandle {
+    // clientCache is a private property
+    return new PhaseTimerTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<PhaseTimer>(REMOTE_MODULE.tables.phase_timer));
+  }
+
   get player(): PlayerTableHandle {
     // clientCache is a private property
     return new PlayerTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<Player>(REMOTE_MODULE.tables.player));
@@ -637,10 +1681,30 @@ export class RemoteTables {
     return new PlayerItemTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<PlayerItem>(REMOTE_MODULE.tables.player_item));
   }
 
+  get pveCombat(): PveCombatTableHandle {
+    // clientCache is a private property
+    return new PveCombatTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<PveCombat>(REMOTE_MODULE.tables.pve_combat));
+  }
+
+  get roundIncome(): RoundIncomeTableHandle {
+    // clientCache is a private property
+    return new RoundIncomeTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<RoundIncome>(REMOTE_MODULE.tables.round_income));
+  }
+
+  get schemaVersion(): SchemaVersionTableHandle {
+    // clientCache is a private property
+    return new SchemaVersionTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<SchemaVersion>(REMOTE_MODULE.tables.schema_version));
+  }
+
   get shopCrew(): ShopCrewTableHandle {
     // clientCache is a private property
     return new ShopCrewTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<ShopCrew>(REMOTE_MODULE.tables.shop_crew));
   }
+
+  get shopOdds(): ShopOddsTableHandle {
+    // clientCache is a private property
+    return new ShopOddsTableHandle((this.connection as unknown as { clientCache: __ClientCache }).clientCache.getOrCreateTable<ShopOdds>(REMOTE_MODULE.tables.shop_odds));
+  }
 }
 
 export class SubscriptionBuilder extends __SubscriptionBuilderImpl<RemoteTables, RemoteReducers, SetReducerFlags> { }
@@ -29,14 +29,9 @@ import {
 import * as ItemComponentVariants from './item_component_variants'
 
 // The tagged union or sum type for the algebraic type `ItemComponent`.
-export type ItemComponent = ItemComponentVariants.Cutlass |
-  ItemComponentVariants.SniperGoggles |
-  ItemComponentVariants.ShellDial |
-  ItemComponentVariants.ToneDial |
-  ItemComponentVariants.SeastoneFragment |
-  ItemComponentVariants.TidalCloak |
-  ItemComponentVariants.EnergyDrink |
-  ItemComponentVariants.Meat;
+export type ItemComponent = ItemComponentVariants.Sword |
+  ItemComponentVariants.Ring |
+  ItemComponentVariants.Gloves;
 
 // A value with helper functions to construct the type.
 export const ItemComponent = {
@@ -46,26 +41,16 @@ export const ItemComponent = {
   // assert!(foo.tag === "A");
   // assert!(foo.value === 42);
   // ```
-  Cutlass: { tag: "Cutlass" } as const,
-  SniperGoggles: { tag: "SniperGoggles" } as const,
-  ShellDial: { tag: "ShellDial" } as const,
-  ToneDial: { tag: "ToneDial" } as const,
-  SeastoneFragment: { tag: "SeastoneFragment" } as const,
-  TidalCloak: { tag: "TidalCloak" } as const,
-  EnergyDrink: { tag: "EnergyDrink" } as const,
-  Meat: { tag: "Meat" } as const,
+  Sword: { tag: "Sword" } as const,
+  Ring: { tag: "Ring" } as const,
+  Gloves: { tag: "Gloves" } as const,
 
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
     return __AlgebraicTypeValue.Sum({
       variants: [
-        { name: "Cutlass", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "SniperGoggles", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "ShellDial", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "ToneDial", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "SeastoneFragment", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "TidalCloak", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "EnergyDrink", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "Meat", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "Sword", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "Ring", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "Gloves", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
       ]
     });
   },
@@ -27,12 +27,7 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
 } from "spacetimedb";
 
-export type Cutlass = { tag: "Cutlass" };
-export type SniperGoggles = { tag: "SniperGoggles" };
-export type ShellDial = { tag: "ShellDial" };
-export type ToneDial = { tag: "ToneDial" };
-export type SeastoneFragment = { tag: "SeastoneFragment" };
-export type TidalCloak = { tag: "TidalCloak" };
-export type EnergyDrink = { tag: "EnergyDrink" };
-export type Meat = { tag: "Meat" };
+export type Sword = { tag: "Sword" };
+export type Ring = { tag: "Ring" };
+export type Gloves = { tag: "Gloves" };
 
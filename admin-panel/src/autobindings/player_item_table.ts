@@ -27,9 +27,9 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
 } from "spacetimedb";
 import { PlayerItem } from "./player_item_type";
-import { ItemComponent } from "./item_component_type";
+import { Item } from "./item_type";
 // Mark import as potentially unused
-declare type __keep_ItemComponent = ItemComponent;
+declare type __keep_Item = Item;
 
 import { type EventContext, type Reducer, RemoteReducers, RemoteTables } from ".";
 declare type __keep = [EventContext, Reducer, RemoteReducers, RemoteTables];
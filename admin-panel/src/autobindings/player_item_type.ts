@@ -26,15 +26,16 @@ import {
   type ReducerEventContextInterface as __ReducerEventContextInterface,
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
 } from "spacetimedb";
-import { ItemComponent } from "./item_component_type";
+import { Item } from "./item_type";
 // Mark import as potentially unused
-declare type __keep_ItemComponent = ItemComponent;
+declare type __keep_Item = Item;
 
 
 export type PlayerItem = {
   id: bigint,
   owner: __Identity,
-  component: ItemComponent,
+  item: Item,
+  benchSlot: number | undefined,
 };
 /**
  * An object for generated helper functions.
@@ -49,7 +50,8 @@ export const PlayerItem = {
       elements: [
         { name: "id", algebraicType: __AlgebraicTypeValue.U64},
         { name: "owner", algebraicType: __AlgebraicTypeValue.createIdentityType()},
-        { name: "component", algebraicType: ItemComponent.getTypeScriptAlgebraicType()},
+        { name: "item", algebraicType: Item.getTypeScriptAlgebraicType()},
+        { name: "benchSlot", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U8)},
       ]
     });
   },
@@ -35,11 +35,19 @@ export type Player = {
   identity: __Identity,
   name: string,
   berries: number,
+  xp: number,
+  level: number,
+  hp: number,
   bounty: number,
   wins: number,
+  winStreak: number,
   losses: number,
   shipType: ShipType,
   online: boolean,
+  lossStreak: number,
+  shopLocked: boolean,
+  eliminated: boolean,
+  placement: number | undefined,
 };
 /**
  * An object for generated helper functions.
@@ -55,11 +63,19 @@ export const Player = {
         { name: "identity", algebraicType: __AlgebraicTypeValue.createIdentityType()},
         { name: "name", algebraicType: __AlgebraicTypeValue.String},
         { name: "berries", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "xp", algebraicType: __AlgebraicTypeValue.U8},
+        { name: "level", algebraicType: __AlgebraicTypeValue.U8},
+        { name: "hp", algebraicType: __AlgebraicTypeValue.U8},
         { name: "bounty", algebraicType: __AlgebraicTypeValue.U32},
         { name: "wins", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "winStreak", algebraicType: __AlgebraicTypeValue.U32},
         { name: "losses", algebraicType: __AlgebraicTypeValue.U32},
         { name: "shipType", algebraicType: ShipType.getTypeScriptAlgebraicType()},
         { name: "online", algebraicType: __AlgebraicTypeValue.Bool},
+        { name: "lossStreak", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "shopLocked", algebraicType: __AlgebraicTypeValue.Bool},
+        { name: "eliminated", algebraicType: __AlgebraicTypeValue.Bool},
+        { name: "placement", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U8)},
       ]
     });
   },
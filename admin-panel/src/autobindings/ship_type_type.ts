@@ -30,8 +30,14 @@ import * as ShipTypeVariants from './ship_type_variants'
 
 // The tagged union or sum type for the algebraic type `ShipType`.
 export type ShipType = ShipTypeVariants.Raft |
-  ShipTypeVariants.GoingMerry |
-  ShipTypeVariants.ThousandSunny;
+  ShipTypeVariants.ThousandSunny |
+  ShipTypeVariants.RedForce |
+  ShipTypeVariants.Naglfar |
+  ShipTypeVariants.SaberOfXebec |
+  ShipTypeVariants.PolarTang |
+  ShipTypeVariants.BigTopBlaster |
+  ShipTypeVariants.MobyDick |
+  ShipTypeVariants.QueenMama;
 
 // A value with helper functions to construct the type.
 export const ShipType = {
@@ -42,15 +48,27 @@ export const ShipType = {
   // assert!(foo.value === 42);
   // ```
   Raft: { tag: "Raft" } as const,
-  GoingMerry: { tag: "GoingMerry" } as const,
   ThousandSunny: { tag: "ThousandSunny" } as const,
+  RedForce: { tag: "RedForce" } as const,
+  Naglfar: { tag: "Naglfar" } as const,
+  SaberOfXebec: { tag: "SaberOfXebec" } as const,
+  PolarTang: { tag: "PolarTang" } as const,
+  BigTopBlaster: { tag: "BigTopBlaster" } as const,
+  MobyDick: { tag: "MobyDick" } as const,
+  QueenMama: { tag: "QueenMama" } as const,
 
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
     return __AlgebraicTypeValue.Sum({
       variants: [
         { name: "Raft", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-        { name: "GoingMerry", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
         { name: "ThousandSunny", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "RedForce", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "Naglfar", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "SaberOfXebec", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "PolarTang", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "BigTopBlaster", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "MobyDick", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "QueenMama", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
       ]
     });
   },
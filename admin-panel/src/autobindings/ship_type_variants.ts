@@ -28,6 +28,12 @@ import {
 } from "spacetimedb";
 
 export type Raft = { tag: "Raft" };
-export type GoingMerry = { tag: "GoingMerry" };
 export type ThousandSunny = { tag: "ThousandSunny" };
+export type RedForce = { tag: "RedForce" };
+export type Naglfar = { tag: "Naglfar" };
+export type SaberOfXebec = { tag: "SaberOfXebec" };
+export type PolarTang = { tag: "PolarTang" };
+export type BigTopBlaster = { tag: "BigTopBlaster" };
+export type MobyDick = { tag: "MobyDick" };
+export type QueenMama = { tag: "QueenMama" };
 
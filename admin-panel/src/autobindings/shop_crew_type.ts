@@ -39,12 +39,15 @@ export type ShopCrew = {
   player: __Identity,
   name: string,
   rarity: CrewRarity,
-  trait1: CrewTrait,
-  trait2: CrewTrait | undefined,
+  traits: CrewTrait[],
   maxHp: number,
+  abilityPower: number,
   attack: number,
+  attackSpeed: number,
   defense: number,
+  magicResistance: number,
   cost: number,
+  templateId: bigint,
 };
 /**
  * An object for generated helper functions.
@@ -61,12 +64,15 @@ export const ShopCrew = {
         { name: "player", algebraicType: __AlgebraicTypeValue.createIdentityType()},
         { name: "name", algebraicType: __AlgebraicTypeValue.String},
         { name: "rarity", algebraicType: CrewRarity.getTypeScriptAlgebraicType()},
-        { name: "trait1", algebraicType: CrewTrait.getTypeScriptAlgebraicType()},
-        { name: "trait2", algebraicType: __AlgebraicTypeValue.createOptionType(CrewTrait.getTypeScriptAlgebraicType())},
+        { name: "traits", algebraicType: __AlgebraicTypeValue.Array(CrewTrait.getTypeScriptAlgebraicType())},
         { name: "maxHp", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "abilityPower", algebraicType: __AlgebraicTypeValue.U32},
         { name: "attack", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "attackSpeed", algebraicType: __AlgebraicTypeValue.F32},
         { name: "defense", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "magicResistance", algebraicType: __AlgebraicTypeValue.U32},
         { name: "cost", algebraicType: __AlgebraicTypeValue.U32},
+        { name: "templateId", algebraicType: __AlgebraicTypeValue.U64},
       ]
     });
   },
@@ -25,50 +25,46 @@ import {
   type EventContextInterface as __EventContextInterface,
   type ReducerEventContextInterface as __ReducerEventContextInterface,
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
-  type TableHandle as __TableHandle,
 } from "spacetimedb";
-import * as TreasureIslandTypeVariants from './treasure_island_type_variants'
+import * as TemplateSyncPolicyVariants from './template_sync_policy_variants'
 
-// The tagged union or sum type for the algebraic type `TreasureIslandType`.
-export type TreasureIslandType = TreasureIslandTypeVariants.SmallIsland |
-  TreasureIslandTypeVariants.MediumIsland |
-  TreasureIslandTypeVariants.LargeIsland;
-
-let _cached_TreasureIslandType_type_value: __AlgebraicTypeType | null = null;
+// The tagged union or sum type for the algebraic type `TemplateSyncPolicy`.
+export type TemplateSyncPolicy = TemplateSyncPolicyVariants.KeepExisting |
+  TemplateSyncPolicyVariants.ResyncShop |
+  TemplateSyncPolicyVariants.ResyncAll;
 
 // A value with helper functions to construct the type.
-export const TreasureIslandType = {
+export const TemplateSyncPolicy = {
   // Helper functions for constructing each variant of the tagged union.
   // ```
   // const foo = Foo.A(42);
   // assert!(foo.tag === "A");
   // assert!(foo.value === 42);
   // ```
-  SmallIsland: { tag: "SmallIsland" } as const,
-  MediumIsland: { tag: "MediumIsland" } as const,
-  LargeIsland: { tag: "LargeIsland" } as const,
+  KeepExisting: { tag: "KeepExisting" } as const,
+  ResyncShop: { tag: "ResyncShop" } as const,
+  ResyncAll: { tag: "ResyncAll" } as const,
 
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
-    if (_cached_TreasureIslandType_type_value) return _cached_TreasureIslandType_type_value;
-    _cached_TreasureIslandType_type_value = __AlgebraicTypeValue.Sum({ variants: [] });
-    _cached_TreasureIslandType_type_value.value.variants.push(
-      { name: "SmallIsland", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-      { name: "MediumIsland", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-      { name: "LargeIsland", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-    );
-    return _cached_TreasureIslandType_type_value;
+    return __AlgebraicTypeValue.Sum({
+      variants: [
+        { name: "KeepExisting", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "ResyncShop", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+        { name: "ResyncAll", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+      ]
+    });
   },
 
-  serialize(writer: __BinaryWriter, value: TreasureIslandType): void {
-      __AlgebraicTypeValue.serializeValue(writer, TreasureIslandType.getTypeScriptAlgebraicType(), value);
+  serialize(writer: __BinaryWriter, value: TemplateSyncPolicy): void {
+      __AlgebraicTypeValue.serializeValue(writer, TemplateSyncPolicy.getTypeScriptAlgebraicType(), value);
   },
 
-  deserialize(reader: __BinaryReader): TreasureIslandType {
-      return __AlgebraicTypeValue.deserializeValue(reader, TreasureIslandType.getTypeScriptAlgebraicType());
+  deserialize(reader: __BinaryReader): TemplateSyncPolicy {
+      return __AlgebraicTypeValue.deserializeValue(reader, TemplateSyncPolicy.getTypeScriptAlgebraicType());
   },
 
 }
 
-export default TreasureIslandType;
+export default TemplateSyncPolicy;
 
 
@@ -28,34 +28,34 @@ import {
   type TableHandle as __TableHandle,
 } from "spacetimedb";
 
-export type GetCurrentTreasureIsland = {};
-let _cached_GetCurrentTreasureIsland_type_value: __AlgebraicTypeType | null = null;
+export type AdminRunMigrations = {};
+let _cached_AdminRunMigrations_type_value: __AlgebraicTypeType | null = null;
 
 /**
  * An object for generated helper functions.
  */
-export const GetCurrentTreasureIsland = {
+export const AdminRunMigrations = {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
   */
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
-    if (_cached_GetCurrentTreasureIsland_type_value) return _cached_GetCurrentTreasureIsland_type_value;
-    _cached_GetCurrentTreasureIsland_type_value = __AlgebraicTypeValue.Product({ elements: [] });
-    _cached_GetCurrentTreasureIsland_type_value.value.elements.push(
+    if (_cached_AdminRunMigrations_type_value) return _cached_AdminRunMigrations_type_value;
+    _cached_AdminRunMigrations_type_value = __AlgebraicTypeValue.Product({ elements: [] });
+    _cached_AdminRunMigrations_type_value.value.elements.push(
     );
-    return _cached_GetCurrentTreasureIsland_type_value;
+    return _cached_AdminRunMigrations_type_value;
   },
 
-  serialize(writer: __BinaryWriter, value: GetCurrentTreasureIsland): void {
-    __AlgebraicTypeValue.serializeValue(writer, GetCurrentTreasureIsland.getTypeScriptAlgebraicType(), value);
+  serialize(writer: __BinaryWriter, value: AdminRunMigrations): void {
+    __AlgebraicTypeValue.serializeValue(writer, AdminRunMigrations.getTypeScriptAlgebraicType(), value);
   },
 
-  deserialize(reader: __BinaryReader): GetCurrentTreasureIsland {
-    return __AlgebraicTypeValue.deserializeValue(reader, GetCurrentTreasureIsland.getTypeScriptAlgebraicType());
+  deserialize(reader: __BinaryReader): AdminRunMigrations {
+    return __AlgebraicTypeValue.deserializeValue(reader, AdminRunMigrations.getTypeScriptAlgebraicType());
   },
 
 }
 
-export default GetCurrentTreasureIsland;
+export default AdminRunMigrations;
 
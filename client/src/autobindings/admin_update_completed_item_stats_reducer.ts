@@ -48,6 +48,12 @@ export type AdminUpdateCompletedItemStats = {
   bonusHpRegen: number | undefined,
   hasSplash: boolean | undefined,
   armorShred: number | undefined,
+  procEvery: number | undefined,
+  procChance: number | undefined,
+  procDamage: number | undefined,
+  procMultiplier: number | undefined,
+  procShots: number | undefined,
+  procStunSeconds: number | undefined,
 };
 let _cached_AdminUpdateCompletedItemStats_type_value: __AlgebraicTypeType | null = null;
 
@@ -78,6 +84,12 @@ export const AdminUpdateCompletedItemStats = {
       { name: "bonusHpRegen", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.F32) },
       { name: "hasSplash", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.Bool) },
       { name: "armorShred", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.I32) },
+      { name: "procEvery", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U32) },
+      { name: "procChance", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.F32) },
+      { name: "procDamage", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U32) },
+      { name: "procMultiplier", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.F32) },
+      { name: "procShots", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U32) },
+      { name: "procStunSeconds", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.F32) },
     );
     return _cached_AdminUpdateCompletedItemStats_type_value;
   },
@@ -42,6 +42,12 @@ export type Battle = {
   bountyReward: number,
   player1Bounty: number,
   player2Bounty: number,
+  seed: bigint,
+  rngState: bigint,
+  lobbyId: bigint,
+  round: number,
+  settled: boolean,
+  ghost: boolean,
 };
 let _cached_Battle_type_value: __AlgebraicTypeType | null = null;
 
@@ -66,6 +72,12 @@ export const Battle = {
       { name: "bountyReward", algebraicType: __AlgebraicTypeValue.U32 },
       { name: "player1Bounty", algebraicType: __AlgebraicTypeValue.U32 },
       { name: "player2Bounty", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "seed", algebraicType: __AlgebraicTypeValue.U64 },
+      { name: "rngState", algebraicType: __AlgebraicTypeValue.U64 },
+      { name: "lobbyId", algebraicType: __AlgebraicTypeValue.U64 },
+      { name: "round", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "settled", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "ghost", algebraicType: __AlgebraicTypeValue.Bool },
     );
     return _cached_Battle_type_value;
   },
@@ -28,6 +28,12 @@ import {
   type TableHandle as __TableHandle,
 } from "spacetimedb";
 import { BattleUnit } from "./battle_unit_type";
+import { Item } from "./item_type";
+// Mark import as potentially unused
+declare type __keep_Item = Item;
+import { CrewRarity } from "./crew_rarity_type";
+// Mark import as potentially unused
+declare type __keep_CrewRarity = CrewRarity;
 import { DbVector2 } from "./db_vector_2_type";
 // Mark import as potentially unused
 declare type __keep_DbVector2 = DbVector2;
@@ -27,6 +27,12 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
+import { Item } from "./item_type";
+// Mark import as potentially unused
+declare type __keep_Item = Item;
+import { CrewRarity } from "./crew_rarity_type";
+// Mark import as potentially unused
+declare type __keep_CrewRarity = CrewRarity;
 import { DbVector2 } from "./db_vector_2_type";
 // Mark import as potentially unused
 declare type __keep_DbVector2 = DbVector2;
@@ -38,6 +44,8 @@ export type BattleUnit = {
   crewId: bigint,
   owner: __Identity,
   side: number,
+  rarity: CrewRarity,
+  level: number,
   position: DbVector2,
   velocity: DbVector2,
   radius: number,
@@ -48,6 +56,7 @@ export type BattleUnit = {
   abilityPower: number,
   magicResist: number,
   attackSpeed: number,
+  attackRange: number,
   critChance: number,
   critDamage: number,
   maxMana: number,
@@ -59,6 +68,11 @@ export type BattleUnit = {
   abilityCooldown: number,
   isStunned: boolean,
   stunDuration: number,
+  hpRegen: number,
+  armorShred: number,
+  hasSplash: boolean,
+  attackCount: number,
+  items: Item[],
 };
 let _cached_BattleUnit_type_value: __AlgebraicTypeType | null = null;
 
@@ -79,6 +93,8 @@ export const BattleUnit = {
       { name: "crewId", algebraicType: __AlgebraicTypeValue.U64 },
       { name: "owner", algebraicType: __AlgebraicTypeValue.createIdentityType() },
       { name: "side", algebraicType: __AlgebraicTypeValue.U8 },
+      { name: "rarity", algebraicType: CrewRarity.getTypeScriptAlgebraicType() },
+      { name: "level", algebraicType: __AlgebraicTypeValue.U8 },
       { name: "position", algebraicType: DbVector2.getTypeScriptAlgebraicType() },
       { name: "velocity", algebraicType: DbVector2.getTypeScriptAlgebraicType() },
       { name: "radius", algebraicType: __AlgebraicTypeValue.F32 },
@@ -89,6 +105,7 @@ export const BattleUnit = {
       { name: "abilityPower", algebraicType: __AlgebraicTypeValue.U32 },
       { name: "magicResist", algebraicType: __AlgebraicTypeValue.U32 },
       { name: "attackSpeed", algebraicType: __AlgebraicTypeValue.F32 },
+      { name: "attackRange", algebraicType: __AlgebraicTypeValue.F32 },
       { name: "critChance", algebraicType: __AlgebraicTypeValue.F32 },
       { name: "critDamage", algebraicType: __AlgebraicTypeValue.F32 },
       { name: "maxMana", algebraicType: __AlgebraicTypeValue.U32 },
@@ -100,6 +117,11 @@ export const BattleUnit = {
       { name: "abilityCooldown", algebraicType: __AlgebraicTypeValue.F32 },
       { name: "isStunned", algebraicType: __AlgebraicTypeValue.Bool },
       { name: "stunDuration", algebraicType: __AlgebraicTypeValue.F32 },
+      { name: "hpRegen", algebraicType: __AlgebraicTypeValue.F32 },
+      { name: "armorShred", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "hasSplash", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "attackCount", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "items", algebraicType: __AlgebraicTypeValue.Array(Item.getTypeScriptAlgebraicType()) },
     );
     return _cached_BattleUnit_type_value;
   },
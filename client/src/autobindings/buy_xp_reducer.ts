@@ -28,34 +28,34 @@ import {
   type TableHandle as __TableHandle,
 } from "spacetimedb";
 
-export type CheckWorkshop = {};
-let _cached_CheckWorkshop_type_value: __AlgebraicTypeType | null = null;
+export type BuyXp = {};
+let _cached_BuyXp_type_value: __AlgebraicTypeType | null = null;
 
 /**
  * An object for generated helper functions.
  */
-export const CheckWorkshop = {
+export const BuyXp = {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
   */
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
-    if (_cached_CheckWorkshop_type_value) return _cached_CheckWorkshop_type_value;
-    _cached_CheckWorkshop_type_value = __AlgebraicTypeValue.Product({ elements: [] });
-    _cached_CheckWorkshop_type_value.value.elements.push(
+    if (_cached_BuyXp_type_value) return _cached_BuyXp_type_value;
+    _cached_BuyXp_type_value = __AlgebraicTypeValue.Product({ elements: [] });
+    _cached_BuyXp_type_value.value.elements.push(
     );
-    return _cached_CheckWorkshop_type_value;
+    return _cached_BuyXp_type_value;
   },
 
-  serialize(writer: __BinaryWriter, value: CheckWorkshop): void {
-    __AlgebraicTypeValue.serializeValue(writer, CheckWorkshop.getTypeScriptAlgebraicType(), value);
+  serialize(writer: __BinaryWriter, value: BuyXp): void {
+    __AlgebraicTypeValue.serializeValue(writer, BuyXp.getTypeScriptAlgebraicType(), value);
   },
 
-  deserialize(reader: __BinaryReader): CheckWorkshop {
-    return __AlgebraicTypeValue.deserializeValue(reader, CheckWorkshop.getTypeScriptAlgebraicType());
+  deserialize(reader: __BinaryReader): BuyXp {
+    return __AlgebraicTypeValue.deserializeValue(reader, BuyXp.getTypeScriptAlgebraicType());
   },
 
 }
 
-export default CheckWorkshop;
+export default BuyXp;
 
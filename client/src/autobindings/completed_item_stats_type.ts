@@ -48,6 +48,12 @@ export type CompletedItemStats = {
   bonusHpRegen: number,
   hasSplash: boolean,
   armorShred: number,
+  procEvery: number,
+  procChance: number,
+  procDamage: number,
+  procMultiplier: number,
+  procShots: number,
+  procStunSeconds: number,
 };
 let _cached_CompletedItemStats_type_value: __AlgebraicTypeType | null = null;
 
@@ -78,6 +84,12 @@ export const CompletedItemStats = {
       { name: "bonusHpRegen", algebraicType: __AlgebraicTypeValue.F32 },
       { name: "hasSplash", algebraicType: __AlgebraicTypeValue.Bool },
       { name: "armorShred", algebraicType: __AlgebraicTypeValue.I32 },
+      { name: "procEvery", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "procChance", algebraicType: __AlgebraicTypeValue.F32 },
+      { name: "procDamage", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "procMultiplier", algebraicType: __AlgebraicTypeValue.F32 },
+      { name: "procShots", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "procStunSeconds", algebraicType: __AlgebraicTypeValue.F32 },
     );
     return _cached_CompletedItemStats_type_value;
   },
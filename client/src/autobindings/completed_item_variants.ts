@@ -28,19 +28,10 @@ import {
   type TableHandle as __TableHandle,
 } from "spacetimedb";
 
-export type Yoru = { tag: "Yoru" };
+export type Yooru = { tag: "Yooru" };
 export type Kabuto = { tag: "Kabuto" };
 export type Shusui = { tag: "Shusui" };
-export type ClimaTact = { tag: "ClimaTact" };
-export type ThunderTempo = { tag: "ThunderTempo" };
-export type MirageFlower = { tag: "MirageFlower" };
-export type AdamWood = { tag: "AdamWood" };
-export type SeaKingScale = { tag: "SeaKingScale" };
-export type ThousandSunnyHull = { tag: "ThousandSunnyHull" };
-export type VivrCard = { tag: "VivrCard" };
-export type LogPose = { tag: "LogPose" };
-export type Poneglyph = { tag: "Poneglyph" };
-export type GumGumFruit = { tag: "GumGumFruit" };
-export type GomuGomuNoMi = { tag: "GomuGomuNoMi" };
-export type HakiMastery = { tag: "HakiMastery" };
+export type RingRing = { tag: "RingRing" };
+export type TenTonHammer = { tag: "TenTonHammer" };
+export type ImpactDial = { tag: "ImpactDial" };
 
@@ -28,37 +28,37 @@ import {
   type TableHandle as __TableHandle,
 } from "spacetimedb";
 
-export type ChooseShipUpgrade = {
-  offerId: bigint,
+export type CreateLobby = {
+  name: string,
 };
-let _cached_ChooseShipUpgrade_type_value: __AlgebraicTypeType | null = null;
+let _cached_CreateLobby_type_value: __AlgebraicTypeType | null = null;
 
 /**
  * An object for generated helper functions.
  */
-export const ChooseShipUpgrade = {
+export const CreateLobby = {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
   */
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
-    if (_cached_ChooseShipUpgrade_type_value) return _cached_ChooseShipUpgrade_type_value;
-    _cached_ChooseShipUpgrade_type_value = __AlgebraicTypeValue.Product({ elements: [] });
-    _cached_ChooseShipUpgrade_type_value.value.elements.push(
-      { name: "offerId", algebraicType: __AlgebraicTypeValue.U64 },
+    if (_cached_CreateLobby_type_value) return _cached_CreateLobby_type_value;
+    _cached_CreateLobby_type_value = __AlgebraicTypeValue.Product({ elements: [] });
+    _cached_CreateLobby_type_value.value.elements.push(
+      { name: "name", algebraicType: __AlgebraicTypeValue.String },
     );
-    return _cached_ChooseShipUpgrade_type_value;
+    return _cached_CreateLobby_type_value;
   },
 
-  serialize(writer: __BinaryWriter, value: ChooseShipUpgrade): void {
-    __AlgebraicTypeValue.serializeValue(writer, ChooseShipUpgrade.getTypeScriptAlgebraicType(), value);
+  serialize(writer: __BinaryWriter, value: CreateLobby): void {
+    __AlgebraicTypeValue.serializeValue(writer, CreateLobby.getTypeScriptAlgebraicType(), value);
   },
 
-  deserialize(reader: __BinaryReader): ChooseShipUpgrade {
-    return __AlgebraicTypeValue.deserializeValue(reader, ChooseShipUpgrade.getTypeScriptAlgebraicType());
+  deserialize(reader: __BinaryReader): CreateLobby {
+    return __AlgebraicTypeValue.deserializeValue(reader, CreateLobby.getTypeScriptAlgebraicType());
   },
 
 }
 
-export default ChooseShipUpgrade;
+export default CreateLobby;
 
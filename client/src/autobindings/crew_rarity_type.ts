@@ -31,6 +31,7 @@ import * as CrewRarityVariants from './crew_rarity_variants'
 
 // The tagged union or sum type for the algebraic type `CrewRarity`.
 export type CrewRarity = CrewRarityVariants.Common |
+  CrewRarityVariants.Uncommon |
   CrewRarityVariants.Rare |
   CrewRarityVariants.Epic |
   CrewRarityVariants.Legendary;
@@ -46,6 +47,7 @@ export const CrewRarity = {
   // assert!(foo.value === 42);
   // ```
   Common: { tag: "Common" } as const,
+  Uncommon: { tag: "Uncommon" } as const,
   Rare: { tag: "Rare" } as const,
   Epic: { tag: "Epic" } as const,
   Legendary: { tag: "Legendary" } as const,
@@ -55,6 +57,7 @@ export const CrewRarity = {
     _cached_CrewRarity_type_value = __AlgebraicTypeValue.Sum({ variants: [] });
     _cached_CrewRarity_type_value.value.variants.push(
       { name: "Common", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+      { name: "Uncommon", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
       { name: "Rare", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
       { name: "Epic", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
       { name: "Legendary", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
@@ -29,6 +29,7 @@ import {
 } from "spacetimedb";
 
 export type Common = { tag: "Common" };
+export type Uncommon = { tag: "Uncommon" };
 export type Rare = { tag: "Rare" };
 export type Epic = { tag: "Epic" };
 export type Legendary = { tag: "Legendary" };
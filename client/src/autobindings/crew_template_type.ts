@@ -39,11 +39,13 @@ export type CrewTemplate = {
   id: bigint,
   name: string,
   rarity: CrewRarity,
-  trait1: CrewTrait,
-  trait2: CrewTrait | undefined,
+  traits: CrewTrait[],
   maxHp: number,
+  abilityPower: number,
   attack: number,
+  attackSpeed: number,
   defense: number,
+  magicResistance: number,
   cost: number,
 };
 let _cached_CrewTemplate_type_value: __AlgebraicTypeType | null = null;
@@ -63,11 +65,13 @@ export const CrewTemplate = {
       { name: "id", algebraicType: __AlgebraicTypeValue.U64 },
       { name: "name", algebraicType: __AlgebraicTypeValue.String },
       { name: "rarity", algebraicType: CrewRarity.getTypeScriptAlgebraicType() },
-      { name: "trait1", algebraicType: CrewTrait.getTypeScriptAlgebraicType() },
-      { name: "trait2", algebraicType: __AlgebraicTypeValue.createOptionType(CrewTrait.getTypeScriptAlgebraicType()) },
+      { name: "traits", algebraicType: __AlgebraicTypeValue.Array(CrewTrait.getTypeScriptAlgebraicType()) },
       { name: "maxHp", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "abilityPower", algebraicType: __AlgebraicTypeValue.U32 },
       { name: "attack", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "attackSpeed", algebraicType: __AlgebraicTypeValue.F32 },
       { name: "defense", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "magicResistance", algebraicType: __AlgebraicTypeValue.U32 },
       { name: "cost", algebraicType: __AlgebraicTypeValue.U32 },
     );
     return _cached_CrewTemplate_type_value;
@@ -27,30 +27,30 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
-import { TreasureIsland } from "./treasure_island_type";
-import { TreasureIslandType } from "./treasure_island_type_type";
+import { Enemy } from "./enemy_type";
+import { EnemyType } from "./enemy_type_type";
 // Mark import as potentially unused
-declare type __keep_TreasureIslandType = TreasureIslandType;
+declare type __keep_EnemyType = EnemyType;
 
 import { type EventContext, type Reducer, RemoteReducers, RemoteTables } from ".";
 declare type __keep = [EventContext, Reducer, RemoteReducers, RemoteTables];
 
 /**
- * Table handle for the table `treasure_island`.
+ * Table handle for the table `enemy`.
  *
- * Obtain a handle from the [`treasureIsland`] property on [`RemoteTables`],
- * like `ctx.db.treasureIsland`.
+ * Obtain a handle from the [`enemy`] property on [`RemoteTables`],
+ * like `ctx.db.enemy`.
  *
  * Users are encouraged not to explicitly reference this type,
  * but to directly chain method calls,
- * like `ctx.db.treasureIsland.on_insert(...)`.
+ * like `ctx.db.enemy.on_insert(...)`.
  */
-export class TreasureIslandTableHandle<TableName extends string> implements __TableHandle<TableName> {
+export class EnemyTableHandle<TableName extends string> implements __TableHandle<TableName> {
   // phantom type to track the table name
   readonly tableName!: TableName;
-  tableCache: __TableCache<TreasureIsland>;
+  tableCache: __TableCache<Enemy>;
 
-  constructor(tableCache: __TableCache<TreasureIsland>) {
+  constructor(tableCache: __TableCache<Enemy>) {
     this.tableCache = tableCache;
   }
 
@@ -58,24 +58,24 @@ export class TreasureIslandTableHandle<TableName extends string> implements __Ta
     return this.tableCache.count();
   }
 
-  iter(): Iterable<TreasureIsland> {
+  iter(): Iterable<Enemy> {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `id` unique index on the table `treasure_island`,
+   * Access to the `id` unique index on the table `enemy`,
    * which allows point queries on the field of the same name
-   * via the [`TreasureIslandIdUnique.find`] method.
+   * via the [`EnemyIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.treasureIsland.id().find(...)`.
+   * like `ctx.db.enemy.id().find(...)`.
    *
-   * Get a handle on the `id` unique index on the table `treasure_island`.
+   * Get a handle on the `id` unique index on the table `enemy`.
    */
   id = {
     // Find the subscribed row whose `id` column value is equal to `col_val`,
     // if such a row is present in the client cache.
-    find: (col_val: bigint): TreasureIsland | undefined => {
+    find: (col_val: bigint): Enemy | undefined => {
       for (let row of this.tableCache.iter()) {
         if (__deepEqual(row.id, col_val)) {
           return row;
@@ -84,27 +84,27 @@ export class TreasureIslandTableHandle<TableName extends string> implements __Ta
     },
   };
 
-  onInsert = (cb: (ctx: EventContext, row: TreasureIsland) => void) => {
+  onInsert = (cb: (ctx: EventContext, row: Enemy) => void) => {
     return this.tableCache.onInsert(cb);
   }
 
-  removeOnInsert = (cb: (ctx: EventContext, row: TreasureIsland) => void) => {
+  removeOnInsert = (cb: (ctx: EventContext, row: Enemy) => void) => {
     return this.tableCache.removeOnInsert(cb);
   }
 
-  onDelete = (cb: (ctx: EventContext, row: TreasureIsland) => void) => {
+  onDelete = (cb: (ctx: EventContext, row: Enemy) => void) => {
     return this.tableCache.onDelete(cb);
   }
 
-  removeOnDelete = (cb: (ctx: EventContext, row: TreasureIsland) => void) => {
+  removeOnDelete = (cb: (ctx: EventContext, row: Enemy) => void) => {
     return this.tableCache.removeOnDelete(cb);
   }
 
   // Updates are only defined for tables with primary keys.
-  onUpdate = (cb: (ctx: EventContext, oldRow: TreasureIsland, newRow: TreasureIsland) => void) => {
+  onUpdate = (cb: (ctx: EventContext, oldRow: Enemy, newRow: Enemy) => void) => {
     return this.tableCache.onUpdate(cb);
   }
 
-  removeOnUpdate = (cb: (ctx: EventContext, onRow: TreasureIsland, newRow: TreasureIsland) => void) => {
+  removeOnUpdate = (cb: (ctx: EventContext, onRow: Enemy, newRow: Enemy) => void) => {
     return this.tableCache.removeOnUpdate(cb);
   }}
@@ -27,49 +27,51 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
-import { TreasureRewardType } from "./treasure_reward_type_type";
+import { EnemyType } from "./enemy_type_type";
 // Mark import as potentially unused
-declare type __keep_TreasureRewardType = TreasureRewardType;
+declare type __keep_EnemyType = EnemyType;
 
 
-export type TreasureReward = {
+export type Enemy = {
   id: bigint,
-  treasureIslandId: bigint,
-  rewardType: TreasureRewardType,
-  claimed: boolean,
+  enemyType: EnemyType,
+  maxHp: number,
+  attack: number,
+  defense: number,
 };
-let _cached_TreasureReward_type_value: __AlgebraicTypeType | null = null;
+let _cached_Enemy_type_value: __AlgebraicTypeType | null = null;
 
 /**
  * An object for generated helper functions.
  */
-export const TreasureReward = {
+export const Enemy = {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
   */
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
-    if (_cached_TreasureReward_type_value) return _cached_TreasureReward_type_value;
-    _cached_TreasureReward_type_value = __AlgebraicTypeValue.Product({ elements: [] });
-    _cached_TreasureReward_type_value.value.elements.push(
+    if (_cached_Enemy_type_value) return _cached_Enemy_type_value;
+    _cached_Enemy_type_value = __AlgebraicTypeValue.Product({ elements: [] });
+    _cached_Enemy_type_value.value.elements.push(
       { name: "id", algebraicType: __AlgebraicTypeValue.U64 },
-      { name: "treasureIslandId", algebraicType: __AlgebraicTypeValue.U64 },
-      { name: "rewardType", algebraicType: TreasureRewardType.getTypeScriptAlgebraicType() },
-      { name: "claimed", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "enemyType", algebraicType: EnemyType.getTypeScriptAlgebraicType() },
+      { name: "maxHp", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "attack", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "defense", algebraicType: __AlgebraicTypeValue.U32 },
     );
-    return _cached_TreasureReward_type_value;
+    return _cached_Enemy_type_value;
   },
 
-  serialize(writer: __BinaryWriter, value: TreasureReward): void {
-    __AlgebraicTypeValue.serializeValue(writer, TreasureReward.getTypeScriptAlgebraicType(), value);
+  serialize(writer: __BinaryWriter, value: Enemy): void {
+    __AlgebraicTypeValue.serializeValue(writer, Enemy.getTypeScriptAlgebraicType(), value);
   },
 
-  deserialize(reader: __BinaryReader): TreasureReward {
-    return __AlgebraicTypeValue.deserializeValue(reader, TreasureReward.getTypeScriptAlgebraicType());
+  deserialize(reader: __BinaryReader): Enemy {
+    return __AlgebraicTypeValue.deserializeValue(reader, Enemy.getTypeScriptAlgebraicType());
   },
 
 }
 
-export default TreasureReward;
+export default Enemy;
 
 
@@ -28,20 +28,11 @@ import {
   type TableHandle as __TableHandle,
 } from "spacetimedb";
 
-export type BonusGold = { tag: "BonusGold" };
-export type BonusHealth = { tag: "BonusHealth" };
-export type BonusAttack = { tag: "BonusAttack" };
-export type BonusDefense = { tag: "BonusDefense" };
-export type BonusSpeed = { tag: "BonusSpeed" };
-export type StrawHatBuff = { tag: "StrawHatBuff" };
-export type MarineBuff = { tag: "MarineBuff" };
-export type RevolutionaryBuff = { tag: "RevolutionaryBuff" };
-export type LogiaBuff = { tag: "LogiaBuff" };
-export type ParameciaBuff = { tag: "ParameciaBuff" };
-export type ZoanBuff = { tag: "ZoanBuff" };
-export type GamblerLuck = { tag: "GamblerLuck" };
-export type FastLearner = { tag: "FastLearner" };
-export type Plunderer = { tag: "Plunderer" };
-export type Medic = { tag: "Medic" };
-export type Arsenal = { tag: "Arsenal" };
+export type MarineSwordsman = { tag: "MarineSwordsman" };
+export type MarineRifle = { tag: "MarineRifle" };
+export type Parcifista = { tag: "Parcifista" };
+export type Smoker = { tag: "Smoker" };
+export type Korby = { tag: "Korby" };
+export type Garp = { tag: "Garp" };
+export type Kizaru = { tag: "Kizaru" };
 
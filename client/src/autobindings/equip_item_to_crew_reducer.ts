@@ -28,13 +28,9 @@ import {
   type TableHandle as __TableHandle,
 } from "spacetimedb";
 
-import { ItemComponent } from "./item_component_type";
-// Mark import as potentially unused
-declare type __keep_ItemComponent = ItemComponent;
-
 export type EquipItemToCrew = {
   crewId: bigint,
-  component: ItemComponent,
+  playerItemId: bigint,
 };
 let _cached_EquipItemToCrew_type_value: __AlgebraicTypeType | null = null;
 
@@ -51,7 +47,7 @@ export const EquipItemToCrew = {
     _cached_EquipItemToCrew_type_value = __AlgebraicTypeValue.Product({ elements: [] });
     _cached_EquipItemToCrew_type_value.value.elements.push(
       { name: "crewId", algebraicType: __AlgebraicTypeValue.U64 },
-      { name: "component", algebraicType: ItemComponent.getTypeScriptAlgebraicType() },
+      { name: "playerItemId", algebraicType: __AlgebraicTypeValue.U64 },
     );
     return _cached_EquipItemToCrew_type_value;
   },
@@ -27,30 +27,26 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
-import { PlayerShipUpgrade } from "./player_ship_upgrade_type";
-import { ShipUpgradeType } from "./ship_upgrade_type_type";
-// Mark import as potentially unused
-declare type __keep_ShipUpgradeType = ShipUpgradeType;
-
+import { GameConfig } from "./game_config_type";
 import { type EventContext, type Reducer, RemoteReducers, RemoteTables } from ".";
 declare type __keep = [EventContext, Reducer, RemoteReducers, RemoteTables];
 
 /**
- * Table handle for the table `player_ship_upgrade`.
+ * Table handle for the table `game_config`.
  *
- * Obtain a handle from the [`playerShipUpgrade`] property on [`RemoteTables`],
- * like `ctx.db.playerShipUpgrade`.
+ * Obtain a handle from the [`gameConfig`] property on [`RemoteTables`],
+ * like `ctx.db.gameConfig`.
  *
  * Users are encouraged not to explicitly reference this type,
  * but to directly chain method calls,
- * like `ctx.db.playerShipUpgrade.on_insert(...)`.
+ * like `ctx.db.gameConfig.on_insert(...)`.
  */
-export class PlayerShipUpgradeTableHandle<TableName extends string> implements __TableHandle<TableName> {
+export class GameConfigTableHandle<TableName extends string> implements __TableHandle<TableName> {
   // phantom type to track the table name
   readonly tableName!: TableName;
-  tableCache: __TableCache<PlayerShipUpgrade>;
+  tableCache: __TableCache<GameConfig>;
 
-  constructor(tableCache: __TableCache<PlayerShipUpgrade>) {
+  constructor(tableCache: __TableCache<GameConfig>) {
     this.tableCache = tableCache;
   }
 
@@ -58,24 +54,24 @@ export class PlayerShipUpgradeTableHandle<TableName extends string> implements _
     return this.tableCache.count();
   }
 
-  iter(): Iterable<PlayerShipUpgrade> {
+  iter(): Iterable<GameConfig> {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `id` unique index on the table `player_ship_upgrade`,
+   * Access to the `id` unique index on the table `game_config`,
    * which allows point queries on the field of the same name
-   * via the [`PlayerShipUpgradeIdUnique.find`] method.
+   * via the [`GameConfigIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.playerShipUpgrade.id().find(...)`.
+   * like `ctx.db.gameConfig.id().find(...)`.
    *
-   * Get a handle on the `id` unique index on the table `player_ship_upgrade`.
+   * Get a handle on the `id` unique index on the table `game_config`.
    */
   id = {
     // Find the subscribed row whose `id` column value is equal to `col_val`,
     // if such a row is present in the client cache.
-    find: (col_val: bigint): PlayerShipUpgrade | undefined => {
+    find: (col_val: number): GameConfig | undefined => {
       for (let row of this.tableCache.iter()) {
         if (__deepEqual(row.id, col_val)) {
           return row;
@@ -84,27 +80,27 @@ export class PlayerShipUpgradeTableHandle<TableName extends string> implements _
     },
   };
 
-  onInsert = (cb: (ctx: EventContext, row: PlayerShipUpgrade) => void) => {
+  onInsert = (cb: (ctx: EventContext, row: GameConfig) => void) => {
     return this.tableCache.onInsert(cb);
   }
 
-  removeOnInsert = (cb: (ctx: EventContext, row: PlayerShipUpgrade) => void) => {
+  removeOnInsert = (cb: (ctx: EventContext, row: GameConfig) => void) => {
     return this.tableCache.removeOnInsert(cb);
   }
 
-  onDelete = (cb: (ctx: EventContext, row: PlayerShipUpgrade) => void) => {
+  onDelete = (cb: (ctx: EventContext, row: GameConfig) => void) => {
     return this.tableCache.onDelete(cb);
   }
 
-  removeOnDelete = (cb: (ctx: EventContext, row: PlayerShipUpgrade) => void) => {
+  removeOnDelete = (cb: (ctx: EventContext, row: GameConfig) => void) => {
     return this.tableCache.removeOnDelete(cb);
   }
 
   // Updates are only defined for tables with primary keys.
-  onUpdate = (cb: (ctx: EventContext, oldRow: PlayerShipUpgrade, newRow: PlayerShipUpgrade) => void) => {
+  onUpdate = (cb: (ctx: EventContext, oldRow: GameConfig, newRow: GameConfig) => void) => {
     return this.tableCache.onUpdate(cb);
   }
 
-  removeOnUpdate = (cb: (ctx: EventContext, onRow: PlayerShipUpgrade, newRow: PlayerShipUpgrade) => void) => {
+  removeOnUpdate = (cb: (ctx: EventContext, onRow: GameConfig, newRow: GameConfig) => void) => {
     return this.tableCache.removeOnUpdate(cb);
   }}
@@ -28,37 +28,40 @@ import {
   type TableHandle as __TableHandle,
 } from "spacetimedb";
 
-export type ClaimTreasureIsland = {
-  treasureIslandId: bigint,
+export type GameConfig = {
+  id: number,
+  rerollCost: number,
 };
-let _cached_ClaimTreasureIsland_type_value: __AlgebraicTypeType | null = null;
+let _cached_GameConfig_type_value: __AlgebraicTypeType | null = null;
 
 /**
  * An object for generated helper functions.
  */
-export const ClaimTreasureIsland = {
+export const GameConfig = {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
   */
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
-    if (_cached_ClaimTreasureIsland_type_value) return _cached_ClaimTreasureIsland_type_value;
-    _cached_ClaimTreasureIsland_type_value = __AlgebraicTypeValue.Product({ elements: [] });
-    _cached_ClaimTreasureIsland_type_value.value.elements.push(
-      { name: "treasureIslandId", algebraicType: __AlgebraicTypeValue.U64 },
+    if (_cached_GameConfig_type_value) return _cached_GameConfig_type_value;
+    _cached_GameConfig_type_value = __AlgebraicTypeValue.Product({ elements: [] });
+    _cached_GameConfig_type_value.value.elements.push(
+      { name: "id", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "rerollCost", algebraicType: __AlgebraicTypeValue.U32 },
     );
-    return _cached_ClaimTreasureIsland_type_value;
+    return _cached_GameConfig_type_value;
   },
 
-  serialize(writer: __BinaryWriter, value: ClaimTreasureIsland): void {
-    __AlgebraicTypeValue.serializeValue(writer, ClaimTreasureIsland.getTypeScriptAlgebraicType(), value);
+  serialize(writer: __BinaryWriter, value: GameConfig): void {
+    __AlgebraicTypeValue.serializeValue(writer, GameConfig.getTypeScriptAlgebraicType(), value);
   },
 
-  deserialize(reader: __BinaryReader): ClaimTreasureIsland {
-    return __AlgebraicTypeValue.deserializeValue(reader, ClaimTreasureIsland.getTypeScriptAlgebraicType());
+  deserialize(reader: __BinaryReader): GameConfig {
+    return __AlgebraicTypeValue.deserializeValue(reader, GameConfig.getTypeScriptAlgebraicType());
   },
 
 }
 
-export default ClaimTreasureIsland;
+export default GameConfig;
+
 
@@ -28,7 +28,7 @@ import {
   type TableHandle as __TableHandle,
 } from "spacetimedb";
 
-export type SmallIsland = { tag: "SmallIsland" };
-export type MediumIsland = { tag: "MediumIsland" };
-export type LargeIsland = { tag: "LargeIsland" };
+export type Planning = { tag: "Planning" };
+export type Combat = { tag: "Combat" };
+export type Resolution = { tag: "Resolution" };
 
@@ -30,10 +30,11 @@ import {
 import { ItemComponent as ItemComponentType } from "./item_component_type";
 // Mark import as potentially unused
 declare type __keep_ItemComponentType = ItemComponentType;
+import { CompletedItem as CompletedItemType } from "./completed_item_type";
+// Mark import as potentially unused
+declare type __keep_CompletedItemType = CompletedItemType;
 
 
-export type Gold = { tag: "Gold", value: number };
-export type Item = { tag: "Item", value: ItemComponentType };
-export type Reroll = { tag: "Reroll" };
-export type Experience = { tag: "Experience" };
+export type Component = { tag: "Component", value: ItemComponentType };
+export type Completed = { tag: "Completed", value: CompletedItemType };
 
@@ -27,49 +27,47 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
-import { ShipUpgradeType } from "./ship_upgrade_type_type";
-// Mark import as potentially unused
-declare type __keep_ShipUpgradeType = ShipUpgradeType;
 
-
-export type ShipUpgradeOffer = {
+export type Journey = {
   id: bigint,
-  player: __Identity,
-  upgradeType: ShipUpgradeType,
-  fightNumber: number,
+  playerId: __Identity,
+  currentLocation: bigint | undefined,
+  createdAt: __Timestamp,
+  seed: bigint,
 };
-let _cached_ShipUpgradeOffer_type_value: __AlgebraicTypeType | null = null;
+let _cached_Journey_type_value: __AlgebraicTypeType | null = null;
 
 /**
  * An object for generated helper functions.
  */
-export const ShipUpgradeOffer = {
+export const Journey = {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
   */
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
-    if (_cached_ShipUpgradeOffer_type_value) return _cached_ShipUpgradeOffer_type_value;
-    _cached_ShipUpgradeOffer_type_value = __AlgebraicTypeValue.Product({ elements: [] });
-    _cached_ShipUpgradeOffer_type_value.value.elements.push(
+    if (_cached_Journey_type_value) return _cached_Journey_type_value;
+    _cached_Journey_type_value = __AlgebraicTypeValue.Product({ elements: [] });
+    _cached_Journey_type_value.value.elements.push(
       { name: "id", algebraicType: __AlgebraicTypeValue.U64 },
-      { name: "player", algebraicType: __AlgebraicTypeValue.createIdentityType() },
-      { name: "upgradeType", algebraicType: ShipUpgradeType.getTypeScriptAlgebraicType() },
-      { name: "fightNumber", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "playerId", algebraicType: __AlgebraicTypeValue.createIdentityType() },
+      { name: "currentLocation", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U64) },
+      { name: "createdAt", algebraicType: __AlgebraicTypeValue.createTimestampType() },
+      { name: "seed", algebraicType: __AlgebraicTypeValue.U64 },
     );
-    return _cached_ShipUpgradeOffer_type_value;
+    return _cached_Journey_type_value;
   },
 
-  serialize(writer: __BinaryWriter, value: ShipUpgradeOffer): void {
-    __AlgebraicTypeValue.serializeValue(writer, ShipUpgradeOffer.getTypeScriptAlgebraicType(), value);
+  serialize(writer: __BinaryWriter, value: Journey): void {
+    __AlgebraicTypeValue.serializeValue(writer, Journey.getTypeScriptAlgebraicType(), value);
   },
 
-  deserialize(reader: __BinaryReader): ShipUpgradeOffer {
-    return __AlgebraicTypeValue.deserializeValue(reader, ShipUpgradeOffer.getTypeScriptAlgebraicType());
+  deserialize(reader: __BinaryReader): Journey {
+    return __AlgebraicTypeValue.deserializeValue(reader, Journey.getTypeScriptAlgebraicType());
   },
 
 }
 
-export default ShipUpgradeOffer;
+export default Journey;
 
 
@@ -27,30 +27,30 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
-import { ShipUpgradeOffer } from "./ship_upgrade_offer_type";
-import { ShipUpgradeType } from "./ship_upgrade_type_type";
+import { Lobby } from "./lobby_type";
+import { LobbyStatus } from "./lobby_status_type";
 // Mark import as potentially unused
-declare type __keep_ShipUpgradeType = ShipUpgradeType;
+declare type __keep_LobbyStatus = LobbyStatus;
 
 import { type EventContext, type Reducer, RemoteReducers, RemoteTables } from ".";
 declare type __keep = [EventContext, Reducer, RemoteReducers, RemoteTables];
 
 /**
- * Table handle for the table `ship_upgrade_offer`.
+ * Table handle for the table `lobby`.
  *
- * Obtain a handle from the [`shipUpgradeOffer`] property on [`RemoteTables`],
- * like `ctx.db.shipUpgradeOffer`.
+ * Obtain a handle from the [`lobby`] property on [`RemoteTables`],
+ * like `ctx.db.lobby`.
  *
  * Users are encouraged not to explicitly reference this type,
  * but to directly chain method calls,
- * like `ctx.db.shipUpgradeOffer.on_insert(...)`.
+ * like `ctx.db.lobby.on_insert(...)`.
  */
-export class ShipUpgradeOfferTableHandle<TableName extends string> implements __TableHandle<TableName> {
+export class LobbyTableHandle<TableName extends string> implements __TableHandle<TableName> {
   // phantom type to track the table name
   readonly tableName!: TableName;
-  tableCache: __TableCache<ShipUpgradeOffer>;
+  tableCache: __TableCache<Lobby>;
 
-  constructor(tableCache: __TableCache<ShipUpgradeOffer>) {
+  constructor(tableCache: __TableCache<Lobby>) {
     this.tableCache = tableCache;
   }
 
@@ -58,24 +58,24 @@ export class ShipUpgradeOfferTableHandle<TableName extends string> implements __
     return this.tableCache.count();
   }
 
-  iter(): Iterable<ShipUpgradeOffer> {
+  iter(): Iterable<Lobby> {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `id` unique index on the table `ship_upgrade_offer`,
+   * Access to the `id` unique index on the table `lobby`,
    * which allows point queries on the field of the same name
-   * via the [`ShipUpgradeOfferIdUnique.find`] method.
+   * via the [`LobbyIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.shipUpgradeOffer.id().find(...)`.
+   * like `ctx.db.lobby.id().find(...)`.
    *
-   * Get a handle on the `id` unique index on the table `ship_upgrade_offer`.
+   * Get a handle on the `id` unique index on the table `lobby`.
    */
   id = {
     // Find the subscribed row whose `id` column value is equal to `col_val`,
     // if such a row is present in the client cache.
-    find: (col_val: bigint): ShipUpgradeOffer | undefined => {
+    find: (col_val: bigint): Lobby | undefined => {
       for (let row of this.tableCache.iter()) {
         if (__deepEqual(row.id, col_val)) {
           return row;
@@ -84,27 +84,27 @@ export class ShipUpgradeOfferTableHandle<TableName extends string> implements __
     },
   };
 
-  onInsert = (cb: (ctx: EventContext, row: ShipUpgradeOffer) => void) => {
+  onInsert = (cb: (ctx: EventContext, row: Lobby) => void) => {
     return this.tableCache.onInsert(cb);
   }
 
-  removeOnInsert = (cb: (ctx: EventContext, row: ShipUpgradeOffer) => void) => {
+  removeOnInsert = (cb: (ctx: EventContext, row: Lobby) => void) => {
     return this.tableCache.removeOnInsert(cb);
   }
 
-  onDelete = (cb: (ctx: EventContext, row: ShipUpgradeOffer) => void) => {
+  onDelete = (cb: (ctx: EventContext, row: Lobby) => void) => {
     return this.tableCache.onDelete(cb);
   }
 
-  removeOnDelete = (cb: (ctx: EventContext, row: ShipUpgradeOffer) => void) => {
+  removeOnDelete = (cb: (ctx: EventContext, row: Lobby) => void) => {
     return this.tableCache.removeOnDelete(cb);
   }
 
   // Updates are only defined for tables with primary keys.
-  onUpdate = (cb: (ctx: EventContext, oldRow: ShipUpgradeOffer, newRow: ShipUpgradeOffer) => void) => {
+  onUpdate = (cb: (ctx: EventContext, oldRow: Lobby, newRow: Lobby) => void) => {
     return this.tableCache.onUpdate(cb);
   }
 
-  removeOnUpdate = (cb: (ctx: EventContext, onRow: ShipUpgradeOffer, newRow: ShipUpgradeOffer) => void) => {
+  removeOnUpdate = (cb: (ctx: EventContext, onRow: Lobby, newRow: Lobby) => void) => {
     return this.tableCache.removeOnUpdate(cb);
   }}
@@ -27,51 +27,51 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
-import { ShipUpgradeType } from "./ship_upgrade_type_type";
+import { LobbyStatus } from "./lobby_status_type";
 // Mark import as potentially unused
-declare type __keep_ShipUpgradeType = ShipUpgradeType;
+declare type __keep_LobbyStatus = LobbyStatus;
 
 
-export type ShipUpgradeData = {
+export type Lobby = {
   id: bigint,
-  upgradeType: ShipUpgradeType,
   name: string,
-  description: string,
-  rarity: number,
+  host: __Identity,
+  status: LobbyStatus,
+  createdAt: __Timestamp,
 };
-let _cached_ShipUpgradeData_type_value: __AlgebraicTypeType | null = null;
+let _cached_Lobby_type_value: __AlgebraicTypeType | null = null;
 
 /**
  * An object for generated helper functions.
  */
-export const ShipUpgradeData = {
+export const Lobby = {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
   */
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
-    if (_cached_ShipUpgradeData_type_value) return _cached_ShipUpgradeData_type_value;
-    _cached_ShipUpgradeData_type_value = __AlgebraicTypeValue.Product({ elements: [] });
-    _cached_ShipUpgradeData_type_value.value.elements.push(
+    if (_cached_Lobby_type_value) return _cached_Lobby_type_value;
+    _cached_Lobby_type_value = __AlgebraicTypeValue.Product({ elements: [] });
+    _cached_Lobby_type_value.value.elements.push(
       { name: "id", algebraicType: __AlgebraicTypeValue.U64 },
-      { name: "upgradeType", algebraicType: ShipUpgradeType.getTypeScriptAlgebraicType() },
       { name: "name", algebraicType: __AlgebraicTypeValue.String },
-      { name: "description", algebraicType: __AlgebraicTypeValue.String },
-      { name: "rarity", algebraicType: __AlgebraicTypeValue.U8 },
+      { name: "host", algebraicType: __AlgebraicTypeValue.createIdentityType() },
+      { name: "status", algebraicType: LobbyStatus.getTypeScriptAlgebraicType() },
+      { name: "createdAt", algebraicType: __AlgebraicTypeValue.createTimestampType() },
     );
-    return _cached_ShipUpgradeData_type_value;
+    return _cached_Lobby_type_value;
   },
 
-  serialize(writer: __BinaryWriter, value: ShipUpgradeData): void {
-    __AlgebraicTypeValue.serializeValue(writer, ShipUpgradeData.getTypeScriptAlgebraicType(), value);
+  serialize(writer: __BinaryWriter, value: Lobby): void {
+    __AlgebraicTypeValue.serializeValue(writer, Lobby.getTypeScriptAlgebraicType(), value);
   },
 
-  deserialize(reader: __BinaryReader): ShipUpgradeData {
-    return __AlgebraicTypeValue.deserializeValue(reader, ShipUpgradeData.getTypeScriptAlgebraicType());
+  deserialize(reader: __BinaryReader): Lobby {
+    return __AlgebraicTypeValue.deserializeValue(reader, Lobby.getTypeScriptAlgebraicType());
   },
 
 }
 
-export default ShipUpgradeData;
+export default Lobby;
 
 
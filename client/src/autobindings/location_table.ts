@@ -27,30 +27,33 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
-import { TreasureReward } from "./treasure_reward_type";
-import { TreasureRewardType } from "./treasure_reward_type_type";
+import { Location } from "./location_type";
+import { Item } from "./item_type";
 // Mark import as potentially unused
-declare type __keep_TreasureRewardType = TreasureRewardType;
+declare type __keep_Item = Item;
+import { LocationType } from "./location_type_type";
+// Mark import as potentially unused
+declare type __keep_LocationType = LocationType;
 
 import { type EventContext, type Reducer, RemoteReducers, RemoteTables } from ".";
 declare type __keep = [EventContext, Reducer, RemoteReducers, RemoteTables];
 
 /**
- * Table handle for the table `treasure_reward`.
+ * Table handle for the table `location`.
  *
- * Obtain a handle from the [`treasureReward`] property on [`RemoteTables`],
- * like `ctx.db.treasureReward`.
+ * Obtain a handle from the [`location`] property on [`RemoteTables`],
+ * like `ctx.db.location`.
  *
  * Users are encouraged not to explicitly reference this type,
  * but to directly chain method calls,
- * like `ctx.db.treasureReward.on_insert(...)`.
+ * like `ctx.db.location.on_insert(...)`.
  */
-export class TreasureRewardTableHandle<TableName extends string> implements __TableHandle<TableName> {
+export class LocationTableHandle<TableName extends string> implements __TableHandle<TableName> {
   // phantom type to track the table name
   readonly tableName!: TableName;
-  tableCache: __TableCache<TreasureReward>;
+  tableCache: __TableCache<Location>;
 
-  constructor(tableCache: __TableCache<TreasureReward>) {
+  constructor(tableCache: __TableCache<Location>) {
     this.tableCache = tableCache;
   }
 
@@ -58,24 +61,24 @@ export class TreasureRewardTableHandle<TableName extends string> implements __Ta
     return this.tableCache.count();
   }
 
-  iter(): Iterable<TreasureReward> {
+  iter(): Iterable<Location> {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `id` unique index on the table `treasure_reward`,
+   * Access to the `id` unique index on the table `location`,
    * which allows point queries on the field of the same name
-   * via the [`TreasureRewardIdUnique.find`] method.
+   * via the [`LocationIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.treasureReward.id().find(...)`.
+   * like `ctx.db.location.id().find(...)`.
    *
-   * Get a handle on the `id` unique index on the table `treasure_reward`.
+   * Get a handle on the `id` unique index on the table `location`.
    */
   id = {
     // Find the subscribed row whose `id` column value is equal to `col_val`,
     // if such a row is present in the client cache.
-    find: (col_val: bigint): TreasureReward | undefined => {
+    find: (col_val: bigint): Location | undefined => {
       for (let row of this.tableCache.iter()) {
         if (__deepEqual(row.id, col_val)) {
           return row;
@@ -84,27 +87,27 @@ export class TreasureRewardTableHandle<TableName extends string> implements __Ta
     },
   };
 
-  onInsert = (cb: (ctx: EventContext, row: TreasureReward) => void) => {
+  onInsert = (cb: (ctx: EventContext, row: Location) => void) => {
     return this.tableCache.onInsert(cb);
   }
 
-  removeOnInsert = (cb: (ctx: EventContext, row: TreasureReward) => void) => {
+  removeOnInsert = (cb: (ctx: EventContext, row: Location) => void) => {
     return this.tableCache.removeOnInsert(cb);
   }
 
-  onDelete = (cb: (ctx: EventContext, row: TreasureReward) => void) => {
+  onDelete = (cb: (ctx: EventContext, row: Location) => void) => {
     return this.tableCache.onDelete(cb);
   }
 
-  removeOnDelete = (cb: (ctx: EventContext, row: TreasureReward) => void) => {
+  removeOnDelete = (cb: (ctx: EventContext, row: Location) => void) => {
     return this.tableCache.removeOnDelete(cb);
   }
 
   // Updates are only defined for tables with primary keys.
-  onUpdate = (cb: (ctx: EventContext, oldRow: TreasureReward, newRow: TreasureReward) => void) => {
+  onUpdate = (cb: (ctx: EventContext, oldRow: Location, newRow: Location) => void) => {
     return this.tableCache.onUpdate(cb);
   }
 
-  removeOnUpdate = (cb: (ctx: EventContext, onRow: TreasureReward, newRow: TreasureReward) => void) => {
+  removeOnUpdate = (cb: (ctx: EventContext, onRow: Location, newRow: Location) => void) => {
     return this.tableCache.removeOnUpdate(cb);
   }}
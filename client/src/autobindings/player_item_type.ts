@@ -27,15 +27,16 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
-import { ItemComponent } from "./item_component_type";
+import { Item } from "./item_type";
 // Mark import as potentially unused
-declare type __keep_ItemComponent = ItemComponent;
+declare type __keep_Item = Item;
 
 
 export type PlayerItem = {
   id: bigint,
   owner: __Identity,
-  component: ItemComponent,
+  item: Item,
+  benchSlot: number | undefined,
 };
 let _cached_PlayerItem_type_value: __AlgebraicTypeType | null = null;
 
@@ -53,7 +54,8 @@ export const PlayerItem = {
     _cached_PlayerItem_type_value.value.elements.push(
       { name: "id", algebraicType: __AlgebraicTypeValue.U64 },
       { name: "owner", algebraicType: __AlgebraicTypeValue.createIdentityType() },
-      { name: "component", algebraicType: ItemComponent.getTypeScriptAlgebraicType() },
+      { name: "item", algebraicType: Item.getTypeScriptAlgebraicType() },
+      { name: "benchSlot", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U8) },
     );
     return _cached_PlayerItem_type_value;
   },
@@ -28,9 +28,6 @@ import {
   type TableHandle as __TableHandle,
 } from "spacetimedb";
 import { Player } from "./player_type";
-import { CrewTrait } from "./crew_trait_type";
-// Mark import as potentially unused
-declare type __keep_CrewTrait = CrewTrait;
 import { ShipType } from "./ship_type_type";
 // Mark import as potentially unused
 declare type __keep_ShipType = ShipType;
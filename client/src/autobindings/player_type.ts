@@ -27,9 +27,6 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
-import { CrewTrait } from "./crew_trait_type";
-// Mark import as potentially unused
-declare type __keep_CrewTrait = CrewTrait;
 import { ShipType } from "./ship_type_type";
 // Mark import as potentially unused
 declare type __keep_ShipType = ShipType;
@@ -39,14 +36,19 @@ export type Player = {
   identity: __Identity,
   name: string,
   berries: number,
+  xp: number,
+  level: number,
+  hp: number,
   bounty: number,
   wins: number,
+  winStreak: number,
   losses: number,
   shipType: ShipType,
   online: boolean,
-  fightsCompleted: number,
-  activeTrait: CrewTrait | undefined,
-  traitLevel: number,
+  lossStreak: number,
+  shopLocked: boolean,
+  eliminated: boolean,
+  placement: number | undefined,
 };
 let _cached_Player_type_value: __AlgebraicTypeType | null = null;
 
@@ -65,14 +67,19 @@ export const Player = {
       { name: "identity", algebraicType: __AlgebraicTypeValue.createIdentityType() },
       { name: "name", algebraicType: __AlgebraicTypeValue.String },
       { name: "berries", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "xp", algebraicType: __AlgebraicTypeValue.U8 },
+      { name: "level", algebraicType: __AlgebraicTypeValue.U8 },
+      { name: "hp", algebraicType: __AlgebraicTypeValue.U8 },
       { name: "bounty", algebraicType: __AlgebraicTypeValue.U32 },
       { name: "wins", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "winStreak", algebraicType: __AlgebraicTypeValue.U32 },
       { name: "losses", algebraicType: __AlgebraicTypeValue.U32 },
       { name: "shipType", algebraicType: ShipType.getTypeScriptAlgebraicType() },
       { name: "online", algebraicType: __AlgebraicTypeValue.Bool },
-      { name: "fightsCompleted", algebraicType: __AlgebraicTypeValue.U32 },
-      { name: "activeTrait", algebraicType: __AlgebraicTypeValue.createOptionType(CrewTrait.getTypeScriptAlgebraicType()) },
-      { name: "traitLevel", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "lossStreak", algebraicType: __AlgebraicTypeValue.U32 },
+      { name: "shopLocked", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "eliminated", algebraicType: __AlgebraicTypeValue.Bool },
+      { name: "placement", algebraicType: __AlgebraicTypeValue.createOptionType(__AlgebraicTypeValue.U8) },
     );
     return _cached_Player_type_value;
   },
@@ -27,49 +27,52 @@ import {
   type SubscriptionEventContextInterface as __SubscriptionEventContextInterface,
   type TableHandle as __TableHandle,
 } from "spacetimedb";
-import { ShipUpgradeType } from "./ship_upgrade_type_type";
+import { Item } from "./item_type";
 // Mark import as potentially unused
-declare type __keep_ShipUpgradeType = ShipUpgradeType;
+declare type __keep_Item = Item;
+import { EnemySpawn } from "./enemy_spawn_type";
+// Mark import as potentially unused
+declare type __keep_EnemySpawn = EnemySpawn;
 
 
-export type PlayerShipUpgrade = {
-  id: bigint,
-  owner: __Identity,
-  upgradeType: ShipUpgradeType,
-  acquiredAtFight: number,
+export type PveCombat = {
+  locationId: bigint,
+  enemies: EnemySpawn[],
+  rewardItems: Item[],
+  rewardGold: number,
 };
-let _cached_PlayerShipUpgrade_type_value: __AlgebraicTypeType | null = null;
+let _cached_PveCombat_type_value: __AlgebraicTypeType | null = null;
 
 /**
  * An object for generated helper functions.
  */
-export const PlayerShipUpgrade = {
+export const PveCombat = {
   /**
   * A function which returns this type represented as an AlgebraicType.
   * This function is derived from the AlgebraicType used to generate this type.
   */
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
-    if (_cached_PlayerShipUpgrade_type_value) return _cached_PlayerShipUpgrade_type_value;
-    _cached_PlayerShipUpgrade_type_value = __AlgebraicTypeValue.Product({ elements: [] });
-    _cached_PlayerShipUpgrade_type_value.value.elements.push(
-      { name: "id", algebraicType: __AlgebraicTypeValue.U64 },
-      { name: "owner", algebraicType: __AlgebraicTypeValue.createIdentityType() },
-      { name: "upgradeType", algebraicType: ShipUpgradeType.getTypeScriptAlgebraicType() },
-      { name: "acquiredAtFight", algebraicType: __AlgebraicTypeValue.U32 },
+    if (_cached_PveCombat_type_value) return _cached_PveCombat_type_value;
+    _cached_PveCombat_type_value = __AlgebraicTypeValue.Product({ elements: [] });
+    _cached_PveCombat_type_value.value.elements.push(
+      { name: "locationId", algebraicType: __AlgebraicTypeValue.U64 },
+      { name: "enemies", algebraicType: __AlgebraicTypeValue.Array(EnemySpawn.getTypeScriptAlgebraicType()) },
+      { name: "rewardItems", algebraicType: __AlgebraicTypeValue.Array(Item.getTypeScriptAlgebraicType()) },
+      { name: "rewardGold", algebraicType: __AlgebraicTypeValue.U32 },
     );
-    return _cached_PlayerShipUpgrade_type_value;
+    return _cached_PveCombat_type_value;
   },
 
-  serialize(writer: __BinaryWriter, value: PlayerShipUpgrade): void {
-    __AlgebraicTypeValue.serializeValue(writer, PlayerShipUpgrade.getTypeScriptAlgebraicType(), value);
+  serialize(writer: __BinaryWriter, value: PveCombat): void {
+    __AlgebraicTypeValue.serializeValue(writer, PveCombat.getTypeScriptAlgebraicType(), value);
   },
 
-  deserialize(reader: __BinaryReader): PlayerShipUpgrade {
-    return __AlgebraicTypeValue.deserializeValue(reader, PlayerShipUpgrade.getTypeScriptAlgebraicType());
+  deserialize(reader: __BinaryReader): PveCombat {
+    return __AlgebraicTypeValue.deserializeValue(reader, PveCombat.getTypeScriptAlgebraicType());
   },
 
 }
 
-export default PlayerShipUpgrade;
+export default PveCombat;
 
 
@@ -31,16 +31,14 @@ import * as ShipTypeVariants from './ship_type_variants'
 
 // The tagged union or sum type for the algebraic type `ShipType`.
 export type ShipType = ShipTypeVariants.Raft |
-  ShipTypeVariants.FlyingLamb |
-  ShipTypeVariants.MarineShip |
-  ShipTypeVariants.RevolutionaryShip |
-  ShipTypeVariants.GiantShip |
-  ShipTypeVariants.RedForce |
   ShipTypeVariants.ThousandSunny |
-  ShipTypeVariants.GarpsShip |
-  ShipTypeVariants.RevolutionaryBattleship |
-  ShipTypeVariants.GiantWarship |
-  ShipTypeVariants.RedForceUpgraded;
+  ShipTypeVariants.RedForce |
+  ShipTypeVariants.Naglfar |
+  ShipTypeVariants.SaberOfXebec |
+  ShipTypeVariants.PolarTang |
+  ShipTypeVariants.BigTopBlaster |
+  ShipTypeVariants.MobyDick |
+  ShipTypeVariants.QueenMama;
 
 let _cached_ShipType_type_value: __AlgebraicTypeType | null = null;
 
@@ -53,32 +51,28 @@ export const ShipType = {
   // assert!(foo.value === 42);
   // ```
   Raft: { tag: "Raft" } as const,
-  FlyingLamb: { tag: "FlyingLamb" } as const,
-  MarineShip: { tag: "MarineShip" } as const,
-  RevolutionaryShip: { tag: "RevolutionaryShip" } as const,
-  GiantShip: { tag: "GiantShip" } as const,
-  RedForce: { tag: "RedForce" } as const,
   ThousandSunny: { tag: "ThousandSunny" } as const,
-  GarpsShip: { tag: "GarpsShip" } as const,
-  RevolutionaryBattleship: { tag: "RevolutionaryBattleship" } as const,
-  GiantWarship: { tag: "GiantWarship" } as const,
-  RedForceUpgraded: { tag: "RedForceUpgraded" } as const,
+  RedForce: { tag: "RedForce" } as const,
+  Naglfar: { tag: "Naglfar" } as const,
+  SaberOfXebec: { tag: "SaberOfXebec" } as const,
+  PolarTang: { tag: "PolarTang" } as const,
+  BigTopBlaster: { tag: "BigTopBlaster" } as const,
+  MobyDick: { tag: "MobyDick" } as const,
+  QueenMama: { tag: "QueenMama" } as const,
 
   getTypeScriptAlgebraicType(): __AlgebraicTypeType {
     if (_cached_ShipType_type_value) return _cached_ShipType_type_value;
     _cached_ShipType_type_value = __AlgebraicTypeValue.Sum({ variants: [] });
     _cached_ShipType_type_value.value.variants.push(
       { name: "Raft", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-      { name: "FlyingLamb", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-      { name: "MarineShip", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-      { name: "RevolutionaryShip", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-      { name: "GiantShip", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-      { name: "RedForce", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
       { name: "ThousandSunny", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-      { name: "GarpsShip", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-      { name: "RevolutionaryBattleship", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-      { name: "GiantWarship", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
-      { name: "RedForceUpgraded", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+      { name: "RedForce", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+      { name: "Naglfar", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+      { name: "SaberOfXebec", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+      { name: "PolarTang", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+      { name: "BigTopBlaster", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+      { name: "MobyDick", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
+      { name: "QueenMama", algebraicType: __AlgebraicTypeValue.Product({ elements: [] }) },
     );
     return _cached_ShipType_type_value;
   },
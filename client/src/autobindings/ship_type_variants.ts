@@ -29,14 +29,12 @@ import {
 } from "spacetimedb";
 
 export type Raft = { tag: "Raft" };
-export type FlyingLamb = { tag: "FlyingLamb" };
-export type MarineShip = { tag: "MarineShip" };
-export type RevolutionaryShip = { tag: "RevolutionaryShip" };
-export type GiantShip = { tag: "GiantShip" };
-export type RedForce = { tag: "RedForce" };
 export type ThousandSunny = { tag: "ThousandSunny" };
-export type GarpsShip = { tag: "GarpsShip" };
-export type RevolutionaryBattleship = { tag: "RevolutionaryBattleship" };
-export type GiantWarship = { tag: "GiantWarship" };
-export type RedForceUpgraded = { tag: "RedForceUpgraded" };
+export type RedForce = { tag: "RedForce" };
+export type Naglfar = { tag: "Naglfar" };
+export type SaberOfXebec = { tag: "SaberOfXebec" };
+export type PolarTang = { tag: "PolarTang" };
+export type BigTopBlaster = { tag: "BigTopBlaster" };
+export type MobyDick = { tag: "MobyDick" };
+export type QueenMama = { tag: "QueenMama" };
 
@@ -1096,14 +1096,9 @@ export default class ShipScene extends Phaser.Scene {
   private getItemComponentName(component: any): string {
     const tag = component?.tag || component;
     const nameMap: Record<string, string> = {
-      'Cutlass': 'Cutlass',
-      'SniperGoggles': 'Sniper Goggles',
-      'ShellDial': 'Shell Dial',
-      'ToneDial': 'Tone Dial',
-      'SeastoneFragment': 'Seastone Fragment',
-      'TidalCloak': 'Tidal Cloak',
-      'EnergyDrink': 'Energy Drink',
-      'Meat': 'Meat',
+      'Sword': 'Sword',
+      'Ring': 'Ring',
+      'Gloves': 'Gloves',
     };
     return nameMap[tag] || tag;
   }
@@ -1111,14 +1106,9 @@ export default class ShipScene extends Phaser.Scene {
   private getItemComponentColor(component: any): number {
     const tag = component?.tag || component;
     const colorMap: Record<string, number> = {
-      'Cutlass': 0xff6b6b,           // Red (AD)
-      'SniperGoggles': 0xffa500,     // Orange (Crit)
-      'ShellDial': 0xffff00,         // Yellow (AS)
-      'ToneDial': 0x9370db,          // Purple (AP)
-      'SeastoneFragment': 0x708090,  // Gray (Armor)
-      'TidalCloak': 0x4169e1,        // Blue (MR)
-      'EnergyDrink': 0x00ced1,       // Cyan (Mana)
-      'Meat': 0xff69b4,              // Pink (HP)
+      'Sword': 0xff6b6b,             // Red (AD)
+      'Ring': 0x9370db,              // Purple (AP)
+      'Gloves': 0xffff00,            // Yellow (AS)
     };
     return colorMap[tag] || 0x888888;
   }
@@ -1126,21 +1116,12 @@ export default class ShipScene extends Phaser.Scene {
   private getCompletedItemName(item: any): string {
     const tag = item?.tag || item;
     const nameMap: Record<string, string> = {
-      'Yoru': 'Yoru',
+      'Yooru': 'Yooru',
       'Kabuto': 'Kabuto',
       'Shusui': 'Shusui',
-      'ClimaTact': 'Clima-Tact',
-      'ThunderTempo': 'Thunder Tempo',
-      'MirageFlower': 'Mirage Flower',
-      'AdamWood': 'Adam Wood',
-      'SeaKingScale': 'Sea King Scale',
-      'ThousandSunnyHull': 'Thousand Sunny Hull',
-      'VivrCard': 'Vivre Card',
-      'LogPose': 'Log Pose',
-      'Poneglyph': 'Poneglyph',
-      'GumGumFruit': 'Gum-Gum Fruit',
-      'GomuGomuNoMi': 'Gomu Gomu no Mi',
-      'HakiMastery': 'Haki Mastery',
+      'RingRing': 'RingRing',
+      'TenTonHammer': '10T Hammer',
+      'ImpactDial': 'Impact Dial',
     };
     return nameMap[tag] || tag;
   }
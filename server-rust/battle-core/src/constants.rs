@@ -0,0 +1,25 @@
+// ========== BATTLE CONSTANTS ==========
+
+pub const BATTLE_ARENA_SIZE: f32 = 1600.0; // 1600x1600 battle arena
+pub const BATTLE_TICK_RATE: u32 = 20; // 20 ticks per second (50ms per tick)
+pub const DELTA_TIME: f32 = 1.0 / BATTLE_TICK_RATE as f32; // 0.05 seconds per tick
+pub const MAX_BATTLE_TICKS: u32 = BATTLE_TICK_RATE * 60; // Battles time out after 60 seconds
+
+// Unit constants
+pub const UNIT_RADIUS: f32 = 40.0;
+pub const UNIT_BASE_CRIT_CHANCE: f32 = 0.25;
+pub const UNIT_BASE_CRIT_DAMAGE: f32 = 1.5; // Damage multiplier on crit
+pub const UNIT_MAX_MANA: u32 = 100;
+pub const UNIT_MANA_PER_ATTACK: u32 = 10;
+pub const UNIT_MOVE_SPEED: f32 = 200.0; // Arena units per second
+pub const UNIT_ATTACK_RANGE: f32 = 100.0; // Melee center-to-center distance needed to attack
+pub const UNIT_RANGED_ATTACK_RANGE: f32 = 400.0;
+pub const MIN_ATTACK_SPEED: f32 = 0.1;
+pub const SPLASH_RADIUS: f32 = 150.0; // Around the target of a splash attack
+pub const SPLASH_DAMAGE_RATIO: f32 = 0.5; // Share of the hit dealt to splashed enemies
+
+// Spatial hash grid constants
+pub const GRID_CELL_SIZE: u16 = 200;
+pub const GRID_WIDTH: u16 = 8; // 1600 / 200 = 8
+pub const GRID_HEIGHT: u16 = 8;
+pub const NUM_GRID_CELLS: u16 = GRID_WIDTH * GRID_HEIGHT; // 64 cells
@@ -0,0 +1,37 @@
+use crate::constants::*;
+use crate::rng::BattleRng;
+
+/// Fraction of damage blocked by a resistance stat: resist / (resist + 100)
+pub fn resistance_reduction(resist: u32) -> f32 {
+    let resist = resist as f32;
+    resist / (resist + 100.0)
+}
+
+/// Damage after armor or magic resist mitigation, at least 1
+pub fn mitigate(damage: f32, resist: u32) -> u32 {
+    ((damage * (1.0 - resistance_reduction(resist))).round() as u32).max(1)
+}
+
+/// Physical damage after armor: damage * (1 - armor / (armor + 100)), at least 1
+pub fn calculate_physical_damage(attack: f32, armor: u32) -> u32 {
+    mitigate(attack, armor)
+}
+
+/// Magic damage after magic resist, same curve as armor
+pub fn calculate_magic_damage(damage: f32, magic_resist: u32) -> u32 {
+    mitigate(damage, magic_resist)
+}
+
+/// Roll for a critical strike and return the raw (pre-mitigation) attack damage
+pub fn roll_attack_damage(rng: &mut BattleRng, attack: u32, crit_chance: f32, crit_damage: f32) -> (f32, bool) {
+    if rng.chance(crit_chance) {
+        (attack as f32 * crit_damage, true)
+    } else {
+        (attack as f32, false)
+    }
+}
+
+/// Seconds between two attacks for a given attacks-per-second value
+pub fn attack_cooldown(attack_speed: f32) -> f32 {
+    1.0 / attack_speed.max(MIN_ATTACK_SPEED)
+}
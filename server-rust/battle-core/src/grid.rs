@@ -0,0 +1,160 @@
+use crate::constants::*;
+use crate::unit::CombatUnit;
+use crate::vector::Vec2;
+
+/// Cell index of a position in the battle arena spatial hash grid
+pub fn get_grid_cell(x: f32, y: f32) -> u16 {
+    let cell_x = ((x.max(0.0) / GRID_CELL_SIZE as f32) as u16).min(GRID_WIDTH - 1);
+    let cell_y = ((y.max(0.0) / GRID_CELL_SIZE as f32) as u16).min(GRID_HEIGHT - 1);
+    cell_y * GRID_WIDTH + cell_x
+}
+
+/// Whether two circles overlap
+pub fn circle_collision(ax: f32, ay: f32, ar: f32, bx: f32, by: f32, br: f32) -> bool {
+    let dx = ax - bx;
+    let dy = ay - by;
+    let dist_sq = dx * dx + dy * dy;
+    let min_dist = ar + br;
+    dist_sq < min_dist * min_dist
+}
+
+/// Spatial hash over the living units of one battle, rebuilt every tick.
+/// Buckets hold indices into the unit slice the grid was built from.
+pub struct SpatialGrid {
+    cells: Vec<Vec<usize>>,
+    max_radius: f32, // Largest unit radius, units can reach this far out of their cell
+    drift: f32, // How far units may have moved since the grid was built
+}
+
+impl SpatialGrid {
+    pub fn build(units: &[CombatUnit]) -> Self {
+        let mut cells = vec![Vec::new(); NUM_GRID_CELLS as usize];
+        let mut max_radius: f32 = 0.0;
+        for (index, unit) in units.iter().enumerate() {
+            if unit.is_alive() {
+                cells[get_grid_cell(unit.position.x, unit.position.y) as usize].push(index);
+                max_radius = max_radius.max(unit.radius);
+            }
+        }
+        SpatialGrid { cells, max_radius, drift: 0.0 }
+    }
+
+    /// Keep radius queries exact while units move up to `distance` away from where they were bucketed
+    pub fn with_drift(mut self, distance: f32) -> Self {
+        self.drift = distance;
+        self
+    }
+
+    /// Unit indices in the cells forming a square ring `ring` cells away from (cell_x, cell_y)
+    fn ring(&self, cell_x: i32, cell_y: i32, ring: i32) -> impl Iterator<Item = usize> + '_ {
+        (cell_y - ring..=cell_y + ring)
+            .flat_map(move |y| (cell_x - ring..=cell_x + ring).map(move |x| (x, y)))
+            .filter(move |(x, y)| (x - cell_x).abs() == ring || (y - cell_y).abs() == ring)
+            .filter(|(x, y)| *x >= 0 && *y >= 0 && *x < GRID_WIDTH as i32 && *y < GRID_HEIGHT as i32)
+            .flat_map(|(x, y)| self.cells[(y * GRID_WIDTH as i32 + x) as usize].iter().copied())
+    }
+
+    /// Index of the closest living unit on the opposing side of `units[index]`.
+    /// Searches outwards ring by ring and stops once no closer cell can exist.
+    pub fn nearest_enemy(&self, units: &[CombatUnit], index: usize) -> Option<usize> {
+        let unit = &units[index];
+        let cell = get_grid_cell(unit.position.x, unit.position.y) as i32;
+        let (cell_x, cell_y) = (cell % GRID_WIDTH as i32, cell / GRID_WIDTH as i32);
+        let max_ring = GRID_WIDTH.max(GRID_HEIGHT) as i32;
+
+        let mut best: Option<(usize, f32)> = None;
+        for ring in 0..max_ring {
+            for other in self.ring(cell_x, cell_y, ring) {
+                let candidate = &units[other];
+                if candidate.side == unit.side || !candidate.is_alive() {
+                    continue;
+                }
+                let dist_sq = (candidate.position - unit.position).length_sq();
+                // Ties are broken by index so the result doesn't depend on bucket order
+                let closer = match best {
+                    None => true,
+                    Some((best_index, best_dist_sq)) => {
+                        dist_sq < best_dist_sq || (dist_sq == best_dist_sq && other < best_index)
+                    }
+                };
+                if closer {
+                    best = Some((other, dist_sq));
+                }
+            }
+
+            // Every unit in the next ring is at least `ring` cells away
+            if let Some((_, best_dist_sq)) = best {
+                let ring_distance = ring as f32 * GRID_CELL_SIZE as f32;
+                if best_dist_sq <= ring_distance * ring_distance {
+                    break;
+                }
+            }
+        }
+
+        best.map(|(index, _)| index)
+    }
+
+    /// Indices of all living units whose circle overlaps the given circle
+    pub fn units_in_radius(&self, units: &[CombatUnit], center: Vec2, radius: f32) -> Vec<usize> {
+        // A unit centred in a neighbouring cell can still overlap with its own radius
+        let reach = radius + self.max_radius + self.drift;
+        let min_cell = get_grid_cell(center.x - reach, center.y - reach);
+        let max_cell = get_grid_cell(center.x + reach, center.y + reach);
+        let (min_x, min_y) = (min_cell % GRID_WIDTH, min_cell / GRID_WIDTH);
+        let (max_x, max_y) = (max_cell % GRID_WIDTH, max_cell / GRID_WIDTH);
+
+        let mut result = Vec::new();
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                for &index in &self.cells[(y * GRID_WIDTH + x) as usize] {
+                    let unit = &units[index];
+                    if circle_collision(center.x, center.y, radius, unit.position.x, unit.position.y, unit.radius) {
+                        result.push(index);
+                    }
+                }
+            }
+        }
+        result.sort_unstable();
+        result
+    }
+
+    /// Push apart overlapping living units, each moving half of the overlap.
+    /// Units are at most one cell wide, so checking the neighbouring cells is enough.
+    pub fn resolve_overlaps(&self, units: &mut [CombatUnit]) {
+        for cell in 0..NUM_GRID_CELLS as i32 {
+            let (cell_x, cell_y) = (cell % GRID_WIDTH as i32, cell / GRID_WIDTH as i32);
+            for &a in &self.cells[cell as usize] {
+                for b in self.ring(cell_x, cell_y, 0).chain(self.ring(cell_x, cell_y, 1)) {
+                    if b <= a {
+                        continue;
+                    }
+                    separate(units, a, b);
+                }
+            }
+        }
+    }
+}
+
+fn separate(units: &mut [CombatUnit], a: usize, b: usize) {
+    let (pa, ra) = (units[a].position, units[a].radius);
+    let (pb, rb) = (units[b].position, units[b].radius);
+    if !circle_collision(pa.x, pa.y, ra, pb.x, pb.y, rb) {
+        return;
+    }
+
+    let delta = pb - pa;
+    let distance = delta.magnitude();
+    // Perfectly stacked units get pushed apart along the x axis
+    let direction = if distance > 0.0 { delta * (1.0 / distance) } else { Vec2::new(1.0, 0.0) };
+    let push = direction * ((ra + rb - distance) * 0.5);
+
+    units[a].position = clamp_to_arena(pa - push);
+    units[b].position = clamp_to_arena(pb + push);
+}
+
+pub fn clamp_to_arena(position: Vec2) -> Vec2 {
+    Vec2::new(
+        position.x.clamp(0.0, BATTLE_ARENA_SIZE),
+        position.y.clamp(0.0, BATTLE_ARENA_SIZE),
+    )
+}
@@ -0,0 +1,30 @@
+/// Combat behaviour of a completed item. Numbers are supplied by the module so they can be tuned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ItemEffect {
+    /// Every `every`th attack deals `damage` magic damage to ALL enemies
+    Yooru { every: u32, damage: u32 },
+    /// `chance` for a ranged unit's attack to fire `shots` rapid shots instead of one
+    Kabuto { chance: f32, shots: u32 },
+    /// Every `every`th attack deals `multiplier` times its damage
+    Shusui { every: u32, multiplier: f32 },
+    /// Battle start: `damage` magic damage to a random enemy
+    RingRing { damage: u32 },
+    /// `chance` on hit to stun the target for `stun_seconds`
+    TenTonHammer { chance: f32, stun_seconds: f32 },
+    /// Battle start: `damage` magic damage to the nearest enemy
+    ImpactDial { damage: u32 },
+}
+
+impl ItemEffect {
+    /// Name used when counting procs
+    pub fn name(&self) -> &'static str {
+        match self {
+            ItemEffect::Yooru { .. } => "Yooru",
+            ItemEffect::Kabuto { .. } => "Kabuto",
+            ItemEffect::Shusui { .. } => "Shusui",
+            ItemEffect::RingRing { .. } => "RingRing",
+            ItemEffect::TenTonHammer { .. } => "10T Hammer",
+            ItemEffect::ImpactDial { .. } => "Impact Dial",
+        }
+    }
+}
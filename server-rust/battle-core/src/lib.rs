@@ -0,0 +1,20 @@
+//! Deterministic battle simulation shared by the SpacetimeDB module and offline tools.
+//! Nothing in here depends on SpacetimeDB, so fights can be unit-tested and replayed from a seed.
+
+pub mod constants;
+pub mod damage;
+pub mod grid;
+pub mod items;
+pub mod rng;
+pub mod sim;
+pub mod unit;
+pub mod vector;
+
+pub use constants::*;
+pub use damage::*;
+pub use grid::*;
+pub use items::*;
+pub use rng::*;
+pub use sim::*;
+pub use unit::*;
+pub use vector::*;
@@ -0,0 +1,40 @@
+/// Small seedable RNG (SplitMix64) so a battle replays identically from the same seed.
+/// The whole state is one u64, which lets the module persist it between ticks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BattleRng {
+    state: u64,
+}
+
+impl BattleRng {
+    /// Create an RNG from a seed, or resume one from a previously saved `state()`
+    pub fn new(seed: u64) -> Self {
+        BattleRng { state: seed }
+    }
+
+    pub fn state(&self) -> u64 {
+        self.state
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform float in [0, 1)
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Roll against a probability in [0, 1]. Never consumes randomness for a zero chance.
+    pub fn chance(&mut self, probability: f32) -> bool {
+        probability > 0.0 && self.next_f32() < probability
+    }
+
+    /// Uniform index in [0, upper). `upper` must be non-zero.
+    pub fn gen_range(&mut self, upper: usize) -> usize {
+        (self.next_u64() % upper as u64) as usize
+    }
+}
@@ -0,0 +1,261 @@
+use crate::constants::*;
+use crate::damage::*;
+use crate::grid::*;
+use crate::items::ItemEffect;
+use crate::rng::BattleRng;
+use crate::unit::CombatUnit;
+use crate::vector::Vec2;
+use std::collections::BTreeMap;
+
+/// Counters collected while simulating, used for balance reports
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BattleStats {
+    pub damage_dealt: BTreeMap<u64, u64>, // Unit id -> total damage dealt
+    pub item_procs: BTreeMap<&'static str, u32>, // Proc name -> times triggered
+}
+
+impl BattleStats {
+    pub fn record_damage(&mut self, unit_id: u64, damage: u32) {
+        *self.damage_dealt.entry(unit_id).or_insert(0) += damage as u64;
+    }
+
+    pub fn record_proc(&mut self, name: &'static str) {
+        *self.item_procs.entry(name).or_insert(0) += 1;
+    }
+}
+
+/// A battle between side 0 and side 1, advanced one fixed tick at a time
+#[derive(Clone, Debug)]
+pub struct BattleSim {
+    pub units: Vec<CombatUnit>,
+    pub rng: BattleRng,
+    pub tick: u32,
+    pub stats: BattleStats,
+}
+
+impl BattleSim {
+    pub fn new(units: Vec<CombatUnit>, rng: BattleRng, tick: u32) -> Self {
+        BattleSim { units, rng, tick, stats: BattleStats::default() }
+    }
+
+    /// Move every living unit towards its nearest enemy and attack once in range
+    pub fn step(&mut self) {
+        if self.tick == 0 {
+            self.trigger_battle_start();
+        }
+
+        // Units move during the tick, splash queries on this grid allow for one tick of movement
+        let grid = SpatialGrid::build(&self.units).with_drift(UNIT_MOVE_SPEED * DELTA_TIME);
+
+        for i in 0..self.units.len() {
+            if !self.units[i].is_alive() {
+                continue;
+            }
+
+            let unit = &mut self.units[i];
+            unit.attack_cooldown = (unit.attack_cooldown - DELTA_TIME).max(0.0);
+
+            // Regeneration is applied once per second of battle time
+            if self.tick.is_multiple_of(BATTLE_TICK_RATE) && unit.hp_regen > 0.0 {
+                unit.heal(unit.hp_regen.round() as u32);
+            }
+
+            if unit.is_stunned {
+                unit.stun_duration -= DELTA_TIME;
+                if unit.stun_duration <= 0.0 {
+                    unit.is_stunned = false;
+                    unit.stun_duration = 0.0;
+                }
+                unit.velocity = Vec2::ZERO;
+                continue;
+            }
+
+            let Some(target_index) = grid.nearest_enemy(&self.units, i) else {
+                self.units[i].target_unit_id = None;
+                self.units[i].velocity = Vec2::ZERO;
+                continue;
+            };
+
+            let target_id = self.units[target_index].id;
+            let target_position = self.units[target_index].position;
+            let unit = &mut self.units[i];
+            unit.target_unit_id = Some(target_id);
+
+            let to_target = target_position - unit.position;
+            if to_target.magnitude() > unit.attack_range {
+                unit.velocity = to_target.normalize() * UNIT_MOVE_SPEED;
+                unit.position = clamp_to_arena(unit.position + unit.velocity * DELTA_TIME);
+                continue;
+            }
+
+            unit.velocity = Vec2::ZERO;
+            if unit.attack_cooldown > 0.0 {
+                continue;
+            }
+
+            self.attack(&grid, i, target_index);
+        }
+
+        // Units moved this tick, so bucket them again before separating
+        SpatialGrid::build(&self.units).resolve_overlaps(&mut self.units);
+        self.tick += 1;
+    }
+
+    /// Battle-start item effects, resolved before anyone moves
+    fn trigger_battle_start(&mut self) {
+        let grid = SpatialGrid::build(&self.units);
+
+        for i in 0..self.units.len() {
+            if !self.units[i].is_alive() {
+                continue;
+            }
+
+            for effect in self.units[i].items.clone() {
+                match effect {
+                    ItemEffect::RingRing { damage } => {
+                        let side = self.units[i].side;
+                        let enemies: Vec<usize> = (0..self.units.len())
+                            .filter(|&j| self.units[j].side != side && self.units[j].is_alive())
+                            .collect();
+                        if !enemies.is_empty() {
+                            let target = enemies[self.rng.gen_range(enemies.len())];
+                            self.deal_magic_damage(i, target, damage as f32);
+                            self.stats.record_proc(effect.name());
+                        }
+                    }
+                    ItemEffect::ImpactDial { damage } => {
+                        if let Some(target) = grid.nearest_enemy(&self.units, i) {
+                            self.deal_magic_damage(i, target, damage as f32);
+                            self.stats.record_proc(effect.name());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Resolve one attack of `attacker` on `target`, including on-attack item procs
+    fn attack(&mut self, grid: &SpatialGrid, attacker: usize, target: usize) {
+        let unit = &mut self.units[attacker];
+        unit.attack_count += 1;
+        unit.attack_cooldown = attack_cooldown(unit.attack_speed);
+        unit.current_mana = (unit.current_mana + unit.mana_per_attack).min(unit.max_mana);
+        unit.ability_ready = unit.current_mana >= unit.max_mana;
+
+        let attack_count = unit.attack_count;
+        let is_ranged = unit.is_ranged();
+        let effects = unit.items.clone();
+
+        let mut multiplier = 1.0;
+        let mut shots = 1;
+        for effect in &effects {
+            match *effect {
+                ItemEffect::Shusui { every, multiplier: bonus } if attack_count.is_multiple_of(every) => {
+                    multiplier *= bonus;
+                    self.stats.record_proc(effect.name());
+                }
+                ItemEffect::Kabuto { chance, shots: rapid_shots } if is_ranged && self.rng.chance(chance) => {
+                    shots = shots.max(rapid_shots);
+                    self.stats.record_proc(effect.name());
+                }
+                _ => {}
+            }
+        }
+
+        // Only the first shot carries the empowered multiplier
+        for shot in 0..shots {
+            if !self.units[target].is_alive() {
+                break;
+            }
+            self.hit(grid, attacker, target, if shot == 0 { multiplier } else { 1.0 });
+        }
+
+        for effect in &effects {
+            match *effect {
+                ItemEffect::TenTonHammer { chance, stun_seconds }
+                    if self.units[target].is_alive() && self.rng.chance(chance) =>
+                {
+                    self.units[target].stun(stun_seconds);
+                    self.stats.record_proc(effect.name());
+                }
+                ItemEffect::Yooru { every, damage } if attack_count.is_multiple_of(every) => {
+                    let side = self.units[attacker].side;
+                    for enemy in 0..self.units.len() {
+                        if self.units[enemy].side != side && self.units[enemy].is_alive() {
+                            self.deal_magic_damage(attacker, enemy, damage as f32);
+                        }
+                    }
+                    self.stats.record_proc(effect.name());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// One basic attack hit, rolling for a crit and mitigated by armor.
+    /// Armor shred is applied after the hit, splash damages enemies around the target.
+    fn hit(&mut self, grid: &SpatialGrid, attacker: usize, target: usize, multiplier: f32) {
+        let unit = &self.units[attacker];
+        let attacker_id = unit.id;
+        let armor_shred = unit.armor_shred;
+        let has_splash = unit.has_splash;
+        let (raw_damage, _crit) = roll_attack_damage(&mut self.rng, unit.attack, unit.crit_chance, unit.crit_damage);
+        let raw_damage = raw_damage * multiplier;
+
+        let target_unit = &mut self.units[target];
+        let damage = calculate_physical_damage(raw_damage, target_unit.defense);
+        target_unit.take_damage(damage);
+        target_unit.defense = target_unit.defense.saturating_sub(armor_shred);
+        let (target_side, target_position) = (target_unit.side, target_unit.position);
+        self.stats.record_damage(attacker_id, damage);
+
+        if has_splash {
+            for other in grid.units_in_radius(&self.units, target_position, SPLASH_RADIUS) {
+                // The grid is from the start of the tick, units may have fallen since
+                if other == target || self.units[other].side != target_side || !self.units[other].is_alive() {
+                    continue;
+                }
+                let splash = calculate_physical_damage(raw_damage * SPLASH_DAMAGE_RATIO, self.units[other].defense);
+                self.units[other].take_damage(splash);
+                self.stats.record_damage(attacker_id, splash);
+            }
+        }
+    }
+
+    fn deal_magic_damage(&mut self, source: usize, target: usize, amount: f32) {
+        let source_id = self.units[source].id;
+        let target = &mut self.units[target];
+        let damage = calculate_magic_damage(amount, target.magic_resist);
+        target.take_damage(damage);
+        self.stats.record_damage(source_id, damage);
+    }
+
+    fn remaining_hp(&self, side: u8) -> u32 {
+        self.units.iter().filter(|u| u.side == side).map(|u| u.current_hp).sum()
+    }
+
+    /// Returns the winning side once one side has no living units or the battle timed out.
+    /// On a timeout (or if both sides fall on the same tick) the side with more remaining HP wins,
+    /// ties go to side 0.
+    pub fn winning_side(&self) -> Option<u8> {
+        let side0_hp = self.remaining_hp(0);
+        let side1_hp = self.remaining_hp(1);
+
+        if side0_hp > 0 && side1_hp > 0 && self.tick < MAX_BATTLE_TICKS {
+            return None;
+        }
+
+        Some(if side1_hp > side0_hp { 1 } else { 0 })
+    }
+
+    /// Step until the battle is decided and return the winning side
+    pub fn run(&mut self) -> u8 {
+        loop {
+            if let Some(side) = self.winning_side() {
+                return side;
+            }
+            self.step();
+        }
+    }
+}
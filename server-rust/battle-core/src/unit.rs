@@ -0,0 +1,62 @@
+use crate::constants::UNIT_ATTACK_RANGE;
+use crate::items::ItemEffect;
+use crate::vector::Vec2;
+
+/// Combat state of one unit, mirrored from the module's `battle_unit` row
+#[derive(Clone, Debug, PartialEq)]
+pub struct CombatUnit {
+    pub id: u64,
+    pub side: u8, // 0 = player1, 1 = player2
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub radius: f32,
+    pub max_hp: u32,
+    pub current_hp: u32,
+    pub attack: u32,
+    pub defense: u32,
+    pub ability_power: u32,
+    pub magic_resist: u32,
+    pub attack_speed: f32, // Attacks per second
+    pub attack_range: f32,
+    pub crit_chance: f32,
+    pub crit_damage: f32,
+    pub max_mana: u32,
+    pub current_mana: u32,
+    pub mana_per_attack: u32,
+    pub attack_cooldown: f32, // Seconds until next attack
+    pub target_unit_id: Option<u64>,
+    pub ability_ready: bool,
+    pub ability_cooldown: f32,
+    pub is_stunned: bool,
+    pub stun_duration: f32, // Seconds of stun remaining
+    pub hp_regen: f32, // HP restored per second
+    pub armor_shred: u32, // Armor removed from the target on every hit
+    pub has_splash: bool, // Hits also damage enemies around the target
+    pub attack_count: u32, // Attacks made this battle, drives every-Nth-attack items
+    pub items: Vec<ItemEffect>,
+}
+
+impl CombatUnit {
+    pub fn is_alive(&self) -> bool {
+        self.current_hp > 0
+    }
+
+    pub fn is_ranged(&self) -> bool {
+        self.attack_range > UNIT_ATTACK_RANGE
+    }
+
+    pub fn take_damage(&mut self, damage: u32) {
+        self.current_hp = self.current_hp.saturating_sub(damage);
+    }
+
+    pub fn heal(&mut self, amount: u32) {
+        self.current_hp = (self.current_hp + amount).min(self.max_hp);
+    }
+
+    /// Stun the unit, keeping the longer of the current and new stun
+    pub fn stun(&mut self, seconds: f32) {
+        self.is_stunned = true;
+        self.stun_duration = self.stun_duration.max(seconds);
+        self.velocity = Vec2::ZERO;
+    }
+}
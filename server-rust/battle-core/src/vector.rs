@@ -0,0 +1,62 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vec2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0.0, y: 0.0 };
+
+    pub fn new(x: f32, y: f32) -> Self {
+        Vec2 { x, y }
+    }
+
+    pub fn normalize(&self) -> Vec2 {
+        let d2 = self.x * self.x + self.y * self.y;
+        if d2 > 0.0 {
+            let inv_mag = 1.0 / d2.sqrt();
+            Vec2::new(self.x * inv_mag, self.y * inv_mag)
+        } else {
+            Vec2::ZERO
+        }
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+
+    pub fn length_sq(&self) -> f32 {
+        self.x * self.x + self.y * self.y
+    }
+
+    pub fn distance(&self, other: &Vec2) -> f32 {
+        (*other - *self).magnitude()
+    }
+}
+
+impl std::ops::Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl std::ops::Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl std::ops::Mul<f32> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, scalar: f32) -> Vec2 {
+        Vec2::new(self.x * scalar, self.y * scalar)
+    }
+}
@@ -0,0 +1,218 @@
+//! Offline battle simulator for balance testing crew templates and items.
+//!
+//! Usage: battle-sim <board.json> [--runs N] [--seed S]
+//!
+//! The board file describes both sides:
+//! { "player1": [{ "name": "Zoro", "level": 2, "slot": 0, "items": ["Sword", "Shusui"] }], "player2": [...] }
+//! Every run uses seed S + run index, so results are reproducible.
+
+use battle_core::{BattleRng, BattleSim, CombatUnit, BATTLE_TICK_RATE};
+use battle_with_friends::{crew_from_template, crew_templates, snapshot_crew, to_combat_unit, ItemCatalog};
+use battle_with_friends::{CompletedItem, CrewTemplate, Item, ItemComponent, FIELD_SIZE, MAX_CREW_LEVEL};
+use serde::Deserialize;
+use spacetimedb::Identity;
+use std::collections::BTreeMap;
+use std::process::ExitCode;
+
+const ALL_ITEMS: [Item; 9] = [
+    Item::Component(ItemComponent::Sword),
+    Item::Component(ItemComponent::Ring),
+    Item::Component(ItemComponent::Gloves),
+    Item::Completed(CompletedItem::Yooru),
+    Item::Completed(CompletedItem::Kabuto),
+    Item::Completed(CompletedItem::Shusui),
+    Item::Completed(CompletedItem::RingRing),
+    Item::Completed(CompletedItem::TenTonHammer),
+    Item::Completed(CompletedItem::ImpactDial),
+];
+
+#[derive(Deserialize)]
+struct BoardFile {
+    player1: Vec<BoardCrew>,
+    player2: Vec<BoardCrew>,
+}
+
+#[derive(Deserialize)]
+struct BoardCrew {
+    name: String,
+    #[serde(default = "default_level")]
+    level: u8,
+    slot: u8,
+    #[serde(default)]
+    items: Vec<String>,
+}
+
+fn default_level() -> u8 {
+    1
+}
+
+struct Options {
+    board_path: String,
+    runs: u32,
+    seed: u64,
+}
+
+/// A fielded unit of the simulated boards, with a label for the report
+struct SimUnit {
+    id: u64,
+    side: u8,
+    label: String,
+}
+
+fn parse_args() -> Result<Options, String> {
+    let mut args = std::env::args().skip(1);
+    let mut board_path = None;
+    let mut runs = 1000;
+    let mut seed = 0;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => {
+                runs = args.next().and_then(|v| v.parse().ok()).ok_or("--runs expects a number")?;
+            }
+            "--seed" => {
+                seed = args.next().and_then(|v| v.parse().ok()).ok_or("--seed expects a number")?;
+            }
+            _ if board_path.is_none() => board_path = Some(arg),
+            _ => return Err(format!("Unexpected argument: {}", arg)),
+        }
+    }
+
+    let board_path = board_path.ok_or("Usage: battle-sim <board.json> [--runs N] [--seed S]")?;
+    if runs == 0 {
+        return Err("--runs must be at least 1".to_string());
+    }
+    Ok(Options { board_path, runs, seed })
+}
+
+/// Items are matched by display name ("10T Hammer") or variant name ("TenTonHammer")
+fn parse_item(name: &str) -> Result<Item, String> {
+    ALL_ITEMS
+        .iter()
+        .find(|item| {
+            let variant = match item {
+                Item::Component(component) => format!("{:?}", component),
+                Item::Completed(completed) => format!("{:?}", completed),
+            };
+            item.get_name().eq_ignore_ascii_case(name) || variant.eq_ignore_ascii_case(name)
+        })
+        .copied()
+        .ok_or_else(|| format!("Unknown item: {}", name))
+}
+
+/// Build the battle unit rows of both boards, the same way the server snapshots a battle
+fn build_units(board: &BoardFile, templates: &[CrewTemplate], catalog: &ItemCatalog) -> Result<(Vec<CombatUnit>, Vec<SimUnit>), String> {
+    let mut units = Vec::new();
+    let mut labels = Vec::new();
+
+    for (side, crews) in [(0u8, &board.player1), (1u8, &board.player2)] {
+        let mut used_slots = Vec::new();
+        for entry in crews {
+            let template = templates
+                .iter()
+                .find(|t| t.name.eq_ignore_ascii_case(&entry.name))
+                .ok_or_else(|| format!("Unknown crew: {}", entry.name))?;
+
+            if entry.level == 0 || entry.level > MAX_CREW_LEVEL {
+                return Err(format!("{}: level must be between 1 and {}", entry.name, MAX_CREW_LEVEL));
+            }
+            if entry.slot >= FIELD_SIZE {
+                return Err(format!("{}: slot {} is off the field, slots go up to {}", entry.name, entry.slot, FIELD_SIZE - 1));
+            }
+            if used_slots.contains(&entry.slot) {
+                return Err(format!("Player {} has two crew in slot {}", side + 1, entry.slot));
+            }
+            used_slots.push(entry.slot);
+            if entry.items.len() > 3 {
+                return Err(format!("{}: at most 3 items can be equipped", entry.name));
+            }
+
+            let items = entry.items.iter().map(|name| parse_item(name)).collect::<Result<Vec<_>, _>>()?;
+            let mut crew = crew_from_template(template, Identity::ZERO, entry.level);
+            crew.slot_index = Some(entry.slot);
+            crew.item1 = items.first().copied();
+            crew.item2 = items.get(1).copied();
+            crew.item3 = items.get(2).copied();
+
+            let id = units.len() as u64 + 1;
+            let mut row = snapshot_crew(&crew, 0, side, catalog).ok_or("Crew must be on the field")?;
+            row.id = id;
+            units.push(to_combat_unit(&row, catalog));
+            labels.push(SimUnit {
+                id,
+                side,
+                label: format!("{} (Lv{}, slot {})", template.name, entry.level, entry.slot),
+            });
+        }
+    }
+
+    Ok((units, labels))
+}
+
+fn run() -> Result<(), String> {
+    let options = parse_args()?;
+    let contents = std::fs::read_to_string(&options.board_path)
+        .map_err(|e| format!("Failed to read {}: {}", options.board_path, e))?;
+    let board: BoardFile = serde_json::from_str(&contents)
+        .map_err(|e| format!("Invalid board file {}: {}", options.board_path, e))?;
+
+    let templates = crew_templates()?;
+    let catalog = ItemCatalog::defaults();
+    let (units, labels) = build_units(&board, &templates, &catalog)?;
+
+    let mut wins = [0u32; 2];
+    let mut total_ticks = 0u64;
+    let mut damage_dealt: BTreeMap<u64, u64> = BTreeMap::new();
+    let mut item_procs: BTreeMap<&'static str, u64> = BTreeMap::new();
+
+    for run in 0..options.runs {
+        let mut sim = BattleSim::new(units.clone(), BattleRng::new(options.seed.wrapping_add(run as u64)), 0);
+        let winner = sim.run();
+
+        wins[winner as usize] += 1;
+        total_ticks += sim.tick as u64;
+        for (unit_id, damage) in sim.stats.damage_dealt {
+            *damage_dealt.entry(unit_id).or_insert(0) += damage;
+        }
+        for (name, count) in sim.stats.item_procs {
+            *item_procs.entry(name).or_insert(0) += count as u64;
+        }
+    }
+
+    let runs = options.runs as f64;
+    let average_ticks = total_ticks as f64 / runs;
+    println!("Simulated {} battles (seeds {}..{})", options.runs, options.seed, options.seed.wrapping_add(runs as u64 - 1));
+    println!("Player 1 win rate: {:.1}%", wins[0] as f64 / runs * 100.0);
+    println!("Player 2 win rate: {:.1}%", wins[1] as f64 / runs * 100.0);
+    println!(
+        "Average fight duration: {:.2}s ({:.0} ticks)",
+        average_ticks / BATTLE_TICK_RATE as f64,
+        average_ticks
+    );
+
+    println!("\nAverage damage dealt per battle:");
+    for unit in &labels {
+        let damage = damage_dealt.get(&unit.id).copied().unwrap_or(0);
+        println!("  P{} {:<32} {:>8.1}", unit.side + 1, unit.label, damage as f64 / runs);
+    }
+
+    println!("\nItem procs per battle:");
+    if item_procs.is_empty() {
+        println!("  (none)");
+    }
+    for (name, count) in &item_procs {
+        println!("  {:<34} {:>8.2}", name, *count as f64 / runs);
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
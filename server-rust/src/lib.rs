@@ -16,11 +16,33 @@ pub use systems::*;
 
 /// Initialize the database - called once when the module is first published
 #[spacetimedb::reducer(init)]
-pub fn init(ctx: &ReducerContext) {
+pub fn init(ctx: &ReducerContext) -> Result<(), String> {
     log::info!("Initializing battle-with-friends database...");
 
+    // The identity publishing the module becomes the first admin
+    init_admin(ctx);
+
     // Initialize crew template database (only happens once)
-    init_crew_templates(ctx);
+    init_crew_templates(ctx)?;
+
+    // Seed item combination recipes
+    init_item_recipes(ctx);
+
+    // Seed tunable item stats
+    init_item_stats(ctx);
+
+    // Seed player level thresholds
+    init_level_thresholds(ctx);
+
+    // Seed shop rarity odds per level
+    init_shop_odds(ctx);
+
+    // Seed tunable game rules
+    init_game_config(ctx);
+
+    // Bring the fresh database to the latest schema version
+    run_migrations(ctx);
 
     log::info!("Database initialization complete!");
+    Ok(())
 }
@@ -1,7 +1,20 @@
-use spacetimedb::{ReducerContext, rand::Rng, Table, log};
+use spacetimedb::{Identity, ReducerContext, rand::Rng, Table, log};
 use crate::types::*;
 use crate::tables::*;
+use crate::systems::access::*;
 use crate::systems::ship_upgrade::*;
+use crate::systems::shop::*;
+use crate::systems::combat::*;
+use crate::systems::crew_data::*;
+use crate::systems::elimination::*;
+use crate::systems::game_loop::*;
+use crate::systems::item_stats::*;
+use crate::systems::item_combination::*;
+use crate::systems::leveling::*;
+use crate::systems::matchmaking::*;
+use crate::systems::migrations::*;
+use crate::systems::pool::*;
+use crate::systems::roster::*;
 
 // ========== REDUCERS ==========
 
@@ -16,61 +29,54 @@ pub fn register_player(ctx: &ReducerContext, name: String) -> Result<(), String>
     ctx.db.player().insert(Player {
         identity,
         name,
-        berries: 1000000, // Start with 1,000,000 berries
+        berries: STARTING_BERRIES,
         xp: 0,
         level: 1,
-        hp: 5,
+        hp: STARTING_PLAYER_HP,
         bounty: 0,
         wins: 0,
         win_streak: 0,
         losses: 0,
+        loss_streak: 0,
         ship_type: ShipType::Raft,
         online: true,
+        shop_locked: false,
+        eliminated: false,
+        placement: None,
     });
 
     // Initialize shop with random crew
-    refresh_shop(ctx)?;
+    roll_shop(ctx, identity)?;
 
     Ok(())
 }
 
+/// Reroll the shop for berries
 #[spacetimedb::reducer]
 pub fn refresh_shop(ctx: &ReducerContext) -> Result<(), String> {
     let identity = ctx.sender;
+    ensure_not_eliminated(ctx, identity)?;
 
-    // Clear old shop
-    for shop_crew in ctx.db.shop_crew().player().filter(&identity) {
-        ctx.db.shop_crew().id().delete(shop_crew.id);
-    }
+    let player = ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
 
-    // Get crew templates from database (initialized once on server start)
-    let templates: Vec<_> = ctx.db.crew_template().iter().collect();
+    let player = charge_reroll(player, game_config(ctx).reroll_cost)?;
+    ctx.db.player().identity().update(player);
 
-    if templates.is_empty() {
-        return Err("Crew template database not initialized".to_string());
-    }
+    roll_shop(ctx, identity)
+}
 
-    // Generate 5 random crew from template database
-    let mut rng = ctx.rng();
-    for _ in 0..5 {
-        let index = rng.gen_range(0..templates.len());
-        let template = &templates[index];
+/// Lock or unlock the shop so the next automatic refresh keeps the current offers
+#[spacetimedb::reducer]
+pub fn toggle_shop_lock(ctx: &ReducerContext) -> Result<(), String> {
+    let identity = ctx.sender;
+    ensure_not_eliminated(ctx, identity)?;
 
-        ctx.db.shop_crew().insert(ShopCrew {
-            id: 0,
-            player: identity,
-            name: template.name.clone(),
-            rarity: template.rarity,
-            traits: template.traits.clone(),
-            max_hp: template.max_hp,
-            ability_power: template.ability_power,
-            attack: template.attack,
-            attack_speed: template.attack_speed,
-            defense: template.defense,
-            magic_resistance: template.magic_resistance,
-            cost: template.cost,
-        });
-    }
+    let player = ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+
+    let shop_locked = !player.shop_locked;
+    ctx.db.player().identity().update(Player { shop_locked, ..player });
 
     Ok(())
 }
@@ -78,12 +84,7 @@ pub fn refresh_shop(ctx: &ReducerContext) -> Result<(), String> {
 #[spacetimedb::reducer]
 pub fn buy_crew(ctx: &ReducerContext, shop_crew_id: u64, slot_index: Option<u8>) -> Result<(), String> {
     let identity = ctx.sender;
-
-    if let Some(slot) = slot_index {
-        if slot > 14 {
-            return Err("Invalid slot index (0-14 for field)".to_string());
-        }
-    }
+    ensure_roster_editable(ctx, identity)?;
 
     let shop_crew = ctx.db.shop_crew().id().find(shop_crew_id)
         .ok_or("Shop crew not found")?;
@@ -99,22 +100,39 @@ pub fn buy_crew(ctx: &ReducerContext, shop_crew_id: u64, slot_index: Option<u8>)
         return Err("Not enough Berries".to_string());
     }
 
-    // Check if slot is occupied (if placing on field)
-    if let Some(slot) = slot_index {
-        if ctx.db.crew().owner().filter(&identity)
-            .any(|c| c.slot_index == Some(slot)) {
+    // Place on the requested field slot, otherwise on the first free bench slot.
+    // A copy that completes a star-up merges straight away, so it needs no room on a full bench.
+    let owned: Vec<Crew> = ctx.db.crew().owner().filter(&identity).collect();
+    let placement = match slot_index {
+        Some(slot) => Some(Placement::Field(slot)),
+        None => match first_free_bench_slot(&owned) {
+            Some(bench) => Some(Placement::Bench(bench)),
+            None if completes_star_up(&owned, shop_crew.template_id) => None,
+            None => return Err("Bench is full".to_string()),
+        },
+    };
+    if let Some(placement) = placement {
+        placement.validate()?;
+        if owned.iter().any(|c| Placement::of(c) == Some(placement)) {
             return Err("Slot already occupied".to_string());
         }
+        if matches!(placement, Placement::Field(_)) && fielded_count(&owned) >= max_field_crew(&player) {
+            return Err("No room on the field at your level".to_string());
+        }
     }
 
+    // Claim a copy from the shared pool
+    take_from_pool(ctx, player_lobby_id(ctx, identity), shop_crew.template_id)?;
+
     // Deduct berries
     player.berries -= shop_crew.cost;
     ctx.db.player().identity().update(player);
 
     // Add crew to player
-    ctx.db.crew().insert(Crew {
+    let crew = Crew {
         id: 0,
         owner: identity,
+        template_id: shop_crew.template_id,
         name: shop_crew.name.clone(),
         rarity: shop_crew.rarity,
         traits: shop_crew.traits.clone(),
@@ -124,30 +142,58 @@ pub fn buy_crew(ctx: &ReducerContext, shop_crew_id: u64, slot_index: Option<u8>)
         attack_speed: shop_crew.attack_speed,
         defense: shop_crew.defense,
         magic_resistance: shop_crew.magic_resistance,
+        cost: shop_crew.cost,
         level: 1,
-        slot_index,
-        bench_index: slot_index,
+        slot_index: None,
+        bench_index: None,
         item1: None,
         item2: None,
         item3: None
+    };
+    let crew = ctx.db.crew().insert(match placement {
+        Some(placement) => placement.apply(crew),
+        None => crew,
     });
+    sync_effective_stats(ctx, &crew);
 
     // Remove from shop
     ctx.db.shop_crew().id().delete(shop_crew_id);
 
+    // A third copy merges straight away
+    apply_star_ups(ctx, identity);
+
     Ok(())
 }
 
+/// Spend berries on XP towards the next player level
 #[spacetimedb::reducer]
-pub fn move_crew(ctx: &ReducerContext, crew_id: u64, new_slot: Option<u8>) -> Result<(), String> {
+pub fn buy_xp(ctx: &ReducerContext) -> Result<(), String> {
     let identity = ctx.sender;
+    ensure_not_eliminated(ctx, identity)?;
 
-    if let Some(slot) = new_slot {
-        if slot > 9 {
-            return Err("Invalid slot index".to_string());
-        }
+    let mut player = ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+
+    let thresholds: Vec<LevelThreshold> = ctx.db.level_threshold().iter().collect();
+    if is_max_level(player.level, &thresholds) {
+        return Err("Already at max level".to_string());
+    }
+    if player.berries < BUY_XP_COST {
+        return Err("Not enough Berries".to_string());
     }
 
+    player.berries -= BUY_XP_COST;
+    grant_xp(ctx, player, BUY_XP_AMOUNT);
+
+    Ok(())
+}
+
+/// Sell a crew member: refunds berries, returns its items to the inventory and recalculates the ship
+#[spacetimedb::reducer]
+pub fn sell_crew(ctx: &ReducerContext, crew_id: u64) -> Result<(), String> {
+    let identity = ctx.sender;
+    ensure_roster_editable(ctx, identity)?;
+
     let crew = ctx.db.crew().id().find(crew_id)
         .ok_or("Crew not found")?;
 
@@ -155,23 +201,105 @@ pub fn move_crew(ctx: &ReducerContext, crew_id: u64, new_slot: Option<u8>) -> Re
         return Err("Not your crew".to_string());
     }
 
-    // Check if target slot is occupied
-    if let Some(slot) = new_slot {
-        if let Some(other_crew) = ctx.db.crew().owner().filter(&identity)
-            .find(|c| c.slot_index == Some(slot) && c.id != crew_id) {
-            // Swap positions
-            ctx.db.crew().id().update(Crew {
-                slot_index: crew.slot_index,
-                ..other_crew
-            });
+    let mut player = ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+
+    let refund = sell_value(&crew);
+    player.berries = player.berries.saturating_add(refund);
+    ctx.db.player().identity().update(player);
+
+    return_items_to_inventory(ctx, identity, equipped_items(&crew));
+    return_to_pool(ctx, player_lobby_id(ctx, identity), crew.template_id, copies_in(&crew));
+    delete_crew(ctx, crew_id);
+
+    update_player_ship(ctx, identity);
+
+    log::info!("Player {} sold {} (level {}) for {} berries", identity, crew.name, crew.level, refund);
+    Ok(())
+}
+
+/// Merge any three copies of the same crew at the same level the player owns
+#[spacetimedb::reducer]
+pub fn star_up_crew(ctx: &ReducerContext) -> Result<(), String> {
+    let identity = ctx.sender;
+    ensure_roster_editable(ctx, identity)?;
+
+    ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+
+    let merged = apply_star_ups(ctx, identity);
+    if merged.is_empty() {
+        log::info!("Player {} has no crew to merge", identity);
+    }
+
+    Ok(())
+}
+
+/// Move a crew member to a field slot, or to the first free bench slot when `new_slot` is None
+#[spacetimedb::reducer]
+pub fn move_crew(ctx: &ReducerContext, crew_id: u64, new_slot: Option<u8>) -> Result<(), String> {
+    match new_slot {
+        Some(slot) => move_to_field(ctx, crew_id, slot),
+        None => move_to_bench(ctx, crew_id, None),
+    }
+}
+
+/// Put a crew member on a field slot, swapping with whoever stands there
+#[spacetimedb::reducer]
+pub fn move_to_field(ctx: &ReducerContext, crew_id: u64, slot_index: u8) -> Result<(), String> {
+    let identity = ctx.sender;
+    ensure_roster_editable(ctx, identity)?;
+    let crew = owned_crew(ctx, identity, crew_id)?;
+
+    let player = ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+
+    // Coming from the bench onto an empty slot adds a fielded crew, a swap does not
+    let owned: Vec<Crew> = ctx.db.crew().owner().filter(&identity).collect();
+    let target_taken = owned.iter().any(|c| c.slot_index == Some(slot_index));
+    if crew.slot_index.is_none() && !target_taken && fielded_count(&owned) >= max_field_crew(&player) {
+        return Err("No room on the field at your level".to_string());
+    }
+
+    move_crew_to(ctx, crew, Placement::Field(slot_index))
+}
+
+/// Put a crew member on a bench slot (the first free one if None), swapping with whoever sits there
+#[spacetimedb::reducer]
+pub fn move_to_bench(ctx: &ReducerContext, crew_id: u64, bench_index: Option<u8>) -> Result<(), String> {
+    let identity = ctx.sender;
+    ensure_roster_editable(ctx, identity)?;
+    let crew = owned_crew(ctx, identity, crew_id)?;
+
+    let bench_index = match bench_index {
+        Some(bench_index) => bench_index,
+        None if crew.bench_index.is_some() => return Ok(()),
+        None => {
+            let owned: Vec<Crew> = ctx.db.crew().owner().filter(&identity).collect();
+            first_free_bench_slot(&owned).ok_or("Bench is full")?
         }
+    };
+
+    move_crew_to(ctx, crew, Placement::Bench(bench_index))
+}
+
+/// Exchange the placements of two crew members
+#[spacetimedb::reducer]
+pub fn swap_crew(ctx: &ReducerContext, crew_id: u64, other_crew_id: u64) -> Result<(), String> {
+    let identity = ctx.sender;
+    ensure_roster_editable(ctx, identity)?;
+
+    if crew_id == other_crew_id {
+        return Err("Cannot swap a crew member with itself".to_string());
     }
 
-    // Move crew
-    ctx.db.crew().id().update(Crew {
-        slot_index: new_slot,
-        ..crew
-    });
+    let crew = owned_crew(ctx, identity, crew_id)?;
+    let other = owned_crew(ctx, identity, other_crew_id)?;
+    let placement = Placement::of(&crew).ok_or("Crew has no slot")?;
+    let other_placement = Placement::of(&other).ok_or("Crew has no slot")?;
+
+    ctx.db.crew().id().update(other_placement.apply(crew));
+    ctx.db.crew().id().update(placement.apply(other));
 
     Ok(())
 }
@@ -179,6 +307,15 @@ pub fn move_crew(ctx: &ReducerContext, crew_id: u64, new_slot: Option<u8>) -> Re
 #[spacetimedb::reducer]
 pub fn start_battle(ctx: &ReducerContext) -> Result<(), String> {
     let identity = ctx.sender;
+    ensure_not_eliminated(ctx, identity)?;
+
+    if player_session(ctx, identity).is_some() {
+        return Err("Battles start automatically in the combat phase".to_string());
+    }
+    let lobby_id = player_lobby_id(ctx, identity);
+    if lobby_id != GLOBAL_LOBBY_ID {
+        return Err("Lobby battles start once the host starts the game".to_string());
+    }
 
     // Check if player has crew on field
     let field_crew_count = ctx.db.crew().owner().filter(&identity)
@@ -189,23 +326,28 @@ pub fn start_battle(ctx: &ReducerContext) -> Result<(), String> {
         return Err("Need at least one crew member on the field".to_string());
     }
 
+    let player = ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+    if field_crew_count > max_field_crew(&player) {
+        return Err(format!("Too many crew on the field for level {}", player.level));
+    }
+
     // Update ship based on active trait
     update_player_ship(ctx, identity);
 
     // Get player's current bounty
-    let player = ctx.db.player().identity().find(identity)
-        .ok_or("Player not found")?;
     let player_bounty = player.bounty;
 
     // Find waiting battle or create new one
     if let Some(mut battle) = ctx.db.battle().status().filter(&BattleStatus::WaitingForOpponent)
-        .find(|b| b.player1 != identity) {
+        .find(|b| b.player1 != identity && b.lobby_id == lobby_id) {
         // Join existing battle
         battle.player2 = Some(identity);
         battle.status = BattleStatus::InProgress;
         battle.player2_bounty = player_bounty;
 
-        ctx.db.battle().id().update(battle);
+        let battle = ctx.db.battle().id().update(battle);
+        begin_battle_simulation(ctx, &battle);
     } else {
         // Check if already waiting
         if ctx.db.battle().status().filter(&BattleStatus::WaitingForOpponent)
@@ -214,6 +356,7 @@ pub fn start_battle(ctx: &ReducerContext) -> Result<(), String> {
         }
 
         // Create new battle
+        let seed: u64 = ctx.rng().gen();
         ctx.db.battle().insert(Battle {
             id: 0,
             player1: identity,
@@ -224,16 +367,226 @@ pub fn start_battle(ctx: &ReducerContext) -> Result<(), String> {
             bounty_reward: 0,
             player1_bounty: player_bounty,
             player2_bounty: 0,
+            seed,
+            rng_state: seed,
+            lobby_id,
+            round: 0,
+            settled: false,
+            ghost: false,
         });
     }
 
     Ok(())
 }
 
+/// Scheduled at BATTLE_TICK_RATE for every in-progress battle
+#[spacetimedb::reducer]
+pub fn battle_tick(ctx: &ReducerContext, timer: BattleTickTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("battle_tick may only be invoked by the scheduler".to_string());
+    }
+
+    run_battle_tick(ctx, timer.battle_id);
+
+    Ok(())
+}
+
+/// Scheduled at the deadline of every game session phase
+#[spacetimedb::reducer]
+pub fn phase_deadline(ctx: &ReducerContext, timer: PhaseTimer) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("phase_deadline may only be invoked by the scheduler".to_string());
+    }
+
+    // The phase may already have been advanced by hand
+    let Some(session) = ctx.db.game_session().lobby_id().find(timer.lobby_id) else {
+        return Ok(());
+    };
+    if session.round != timer.round || session.phase != timer.phase {
+        return Ok(());
+    }
+
+    advance_phase(ctx, timer.lobby_id)
+}
+
+/// End the current phase of the sender's lobby game early
+#[spacetimedb::reducer]
+pub fn advance_round(ctx: &ReducerContext) -> Result<(), String> {
+    let identity = ctx.sender;
+    ensure_not_eliminated(ctx, identity)?;
+
+    ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+
+    // The global queue has no rounds, its players keep matching through start_battle
+    let lobby_id = player_lobby_id(ctx, identity);
+    if lobby_id == GLOBAL_LOBBY_ID {
+        return Err("Rounds only run in lobbies, create or join one first".to_string());
+    }
+    if ctx.db.game_session().lobby_id().find(lobby_id).is_none() {
+        return Err("The host has not started the game yet".to_string());
+    }
+
+    // Phase deadlines move a running game on, only the lobby host or an admin may force it
+    let is_host = ctx.db.lobby().id().find(lobby_id)
+        .is_some_and(|lobby| lobby.host == identity);
+    if !is_host && !is_admin(ctx, identity) {
+        return Err("Only the lobby host can end the phase early".to_string());
+    }
+    advance_phase(ctx, lobby_id)
+}
+
+#[spacetimedb::reducer]
+pub fn create_lobby(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let identity = ctx.sender;
+
+    ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+    if ctx.db.lobby_member().identity().find(identity).is_some() {
+        return Err("Already in a lobby".to_string());
+    }
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Lobby name cannot be empty".to_string());
+    }
+
+    // Crew bought in the global lobby go back to its pool
+    clear_player_board(ctx, identity);
+
+    let lobby = ctx.db.lobby().insert(Lobby {
+        id: 0,
+        name,
+        host: identity,
+        status: LobbyStatus::Open,
+        created_at: ctx.timestamp,
+    });
+    ctx.db.lobby_member().insert(LobbyMember {
+        identity,
+        lobby_id: lobby.id,
+        joined_at: ctx.timestamp,
+    });
+    roll_shop(ctx, identity)?;
+
+    log::info!("Player {} created lobby {} ({})", identity, lobby.id, lobby.name);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn join_lobby(ctx: &ReducerContext, lobby_id: u64) -> Result<(), String> {
+    let identity = ctx.sender;
+
+    ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+    if ctx.db.lobby_member().identity().find(identity).is_some() {
+        return Err("Already in a lobby".to_string());
+    }
+
+    let lobby = ctx.db.lobby().id().find(lobby_id)
+        .ok_or("Lobby not found")?;
+    if lobby.status != LobbyStatus::Open {
+        return Err("Lobby is not open".to_string());
+    }
+    if lobby_members(ctx, lobby_id).len() >= MAX_LOBBY_PLAYERS {
+        return Err("Lobby is full".to_string());
+    }
+
+    // Crew bought in the global lobby go back to its pool
+    clear_player_board(ctx, identity);
+
+    ctx.db.lobby_member().insert(LobbyMember {
+        identity,
+        lobby_id,
+        joined_at: ctx.timestamp,
+    });
+    roll_shop(ctx, identity)?;
+
+    log::info!("Player {} joined lobby {}", identity, lobby_id);
+    Ok(())
+}
+
+/// Leave the sender's lobby, forfeiting a running game. The player starts over in the global lobby.
+#[spacetimedb::reducer]
+pub fn leave_lobby(ctx: &ReducerContext) -> Result<(), String> {
+    let identity = ctx.sender;
+
+    let member = ctx.db.lobby_member().identity().find(identity)
+        .ok_or("Not in a lobby")?;
+    let lobby_id = member.lobby_id;
+    let lobby = ctx.db.lobby().id().find(lobby_id)
+        .ok_or("Lobby not found")?;
+
+    let player = ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+    if lobby.status == LobbyStatus::InGame && !player.eliminated {
+        eliminate_player(ctx, identity)?;
+    }
+
+    // Crew go back to the lobby's pool before the player leaves it
+    clear_player_board(ctx, identity);
+    ctx.db.lobby_member().identity().delete(identity);
+    reset_player_for_game(ctx, identity)?;
+    roll_shop(ctx, identity)?;
+
+    let remaining = lobby_members(ctx, lobby_id);
+    if remaining.is_empty() {
+        end_game_session(ctx, lobby_id);
+        clear_lobby_game(ctx, lobby_id);
+        ctx.db.lobby().id().delete(lobby_id);
+        log::info!("Lobby {} closed", lobby_id);
+    } else if lobby.host == identity {
+        // The longest-standing member takes over
+        let host = remaining.iter().min_by_key(|m| m.joined_at).map(|m| m.identity).unwrap_or(identity);
+        ctx.db.lobby().id().update(Lobby { host, ..lobby });
+    }
+
+    log::info!("Player {} left lobby {}", identity, lobby_id);
+    Ok(())
+}
+
+/// Start the game of the sender's lobby, or a rematch once it finished. Every member starts from scratch.
+#[spacetimedb::reducer]
+pub fn start_lobby(ctx: &ReducerContext) -> Result<(), String> {
+    let identity = ctx.sender;
+
+    let member = ctx.db.lobby_member().identity().find(identity)
+        .ok_or("Not in a lobby")?;
+    let lobby = ctx.db.lobby().id().find(member.lobby_id)
+        .ok_or("Lobby not found")?;
+    if lobby.host != identity {
+        return Err("Only the host can start the game".to_string());
+    }
+    if lobby.status == LobbyStatus::InGame {
+        return Err("Game already started".to_string());
+    }
+
+    let members = lobby_members(ctx, lobby.id);
+    if members.len() < 2 {
+        return Err("Need at least two players to start".to_string());
+    }
+
+    for member in &members {
+        reset_player_for_game(ctx, member.identity)?;
+    }
+    clear_lobby_game(ctx, lobby.id);
+
+    let lobby_id = lobby.id;
+    ctx.db.lobby().id().update(Lobby {
+        status: LobbyStatus::InGame,
+        ..lobby
+    });
+    start_game_session(ctx, lobby_id)?;
+
+    log::info!("Lobby {} started a game with {} players", lobby_id, members.len());
+    Ok(())
+}
+
 #[spacetimedb::reducer(client_connected)]
 pub fn client_connected(ctx: &ReducerContext) {
     let identity = ctx.sender;
 
+    // A database upgraded from before the admin table has no admin, publish connects the owner first
+    init_admin(ctx);
+
     if let Some(player) = ctx.db.player().identity().find(identity) {
         ctx.db.player().identity().update(Player {
             online: true,
@@ -260,6 +613,7 @@ pub fn client_disconnected(ctx: &ReducerContext) {
 #[spacetimedb::reducer]
 pub fn equip_item_to_crew(ctx: &ReducerContext, crew_id: u64, player_item_id: u64) -> Result<(), String> {
     let identity = ctx.sender;
+    ensure_roster_editable(ctx, identity)?;
 
     let crew = ctx.db.crew().id().find(crew_id)
         .ok_or("Crew not found")?;
@@ -276,22 +630,19 @@ pub fn equip_item_to_crew(ctx: &ReducerContext, crew_id: u64, player_item_id: u6
         return Err("Not your item".to_string());
     }
 
-    // Check if crew has available item slots (max 3)
-    let updated_crew = if crew.item1.is_none() {
-        Crew { item1: Some(player_item.item), ..crew }
-    } else if crew.item2.is_none() {
-        Crew { item2: Some(player_item.item), ..crew }
-    } else if crew.item3.is_none() {
-        Crew { item3: Some(player_item.item), ..crew }
-    } else {
-        return Err("Crew already has 3 items equipped".to_string());
-    };
+    // Combine with an equipped component if possible, otherwise use a free slot (max 3)
+    let (updated_crew, combined) = equip_item(crew, player_item.item, |a, b| find_recipe(ctx, a, b))?;
+
+    if let Some(result) = combined {
+        log::info!("Crew {} combined {} into {}", crew_id, player_item.item.get_name(), Item::Completed(result).get_name());
+    }
 
     // Remove item from player's inventory
     ctx.db.player_item().id().delete(player_item_id);
 
     // Update crew with equipped item
-    ctx.db.crew().id().update(updated_crew);
+    let crew = ctx.db.crew().id().update(updated_crew);
+    sync_effective_stats(ctx, &crew);
 
     Ok(())
 }
@@ -300,6 +651,7 @@ pub fn equip_item_to_crew(ctx: &ReducerContext, crew_id: u64, player_item_id: u6
 #[spacetimedb::reducer]
 pub fn remove_item_from_crew(ctx: &ReducerContext, crew_id: u64, slot: u8) -> Result<(), String> {
     let identity = ctx.sender;
+    ensure_roster_editable(ctx, identity)?;
 
     let crew = ctx.db.crew().id().find(crew_id)
         .ok_or("Crew not found")?;
@@ -325,7 +677,8 @@ pub fn remove_item_from_crew(ctx: &ReducerContext, crew_id: u64, slot: u8) -> Re
     });
 
     // Update crew
-    ctx.db.crew().id().update(updated_crew);
+    let crew = ctx.db.crew().id().update(updated_crew);
+    sync_effective_stats(ctx, &crew);
 
     Ok(())
 }
@@ -334,6 +687,7 @@ pub fn remove_item_from_crew(ctx: &ReducerContext, crew_id: u64, slot: u8) -> Re
 #[spacetimedb::reducer]
 pub fn add_item_to_inventory(ctx: &ReducerContext, item: Item) -> Result<(), String> {
     let identity = ctx.sender;
+    ensure_not_eliminated(ctx, identity)?;
 
     ctx.db.player_item().insert(PlayerItem {
         id: 0,
@@ -349,6 +703,7 @@ pub fn add_item_to_inventory(ctx: &ReducerContext, item: Item) -> Result<(), Str
 #[spacetimedb::reducer]
 pub fn set_item_bench_slot(ctx: &ReducerContext, player_item_id: u64, bench_slot: Option<u8>) -> Result<(), String> {
     let identity = ctx.sender;
+    ensure_not_eliminated(ctx, identity)?;
 
     let player_item = ctx.db.player_item().id().find(player_item_id)
         .ok_or("Item not found")?;
@@ -371,6 +726,7 @@ pub fn set_item_bench_slot(ctx: &ReducerContext, player_item_id: u64, bench_slot
 #[spacetimedb::reducer]
 pub fn update_ship(ctx: &ReducerContext) -> Result<(), String> {
     let identity = ctx.sender;
+    ensure_not_eliminated(ctx, identity)?;
     update_player_ship(ctx, identity);
     Ok(())
 }
@@ -381,7 +737,7 @@ pub fn complete_battle(ctx: &ReducerContext, battle_id: u64) -> Result<(), Strin
     let identity = ctx.sender;
 
     // Get battle
-    let battle = ctx.db.battle().id().find(&battle_id)
+    let battle = ctx.db.battle().id().find(battle_id)
         .ok_or("Battle not found")?;
 
     // Verify battle is finished
@@ -394,47 +750,482 @@ pub fn complete_battle(ctx: &ReducerContext, battle_id: u64) -> Result<(), Strin
         return Err("Not your battle".to_string());
     }
 
-    let winner = battle.winner.ok_or("Battle has no winner")?;
-    let loser = if winner == battle.player1 {
-        battle.player2.ok_or("Battle has no player2")?
+    if battle.settled {
+        return Err("Battle already completed".to_string());
+    }
+
+    let players = [Some(battle.player1), battle.player2];
+    let queued = battle.round == 0;
+    settle_battle(ctx, battle)?;
+
+    // Battles queued outside a game session have no planning phase, so the next round starts here
+    if queued {
+        for identity in players.into_iter().flatten() {
+            if ensure_not_eliminated(ctx, identity).is_ok() {
+                start_player_round(ctx, identity, 0)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// ========== ADMIN REDUCERS ==========
+
+/// Give another identity access to the admin reducers
+#[spacetimedb::reducer]
+pub fn grant_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    if is_admin(ctx, identity) {
+        return Err("Identity is already an admin".to_string());
+    }
+
+    ctx.db.admin().insert(Admin {
+        identity,
+        granted_by: ctx.sender,
+        granted_at: ctx.timestamp,
+    });
+
+    log::info!("Admin {} granted admin to {}", ctx.sender, identity);
+    Ok(())
+}
+
+/// Remove an identity's access to the admin reducers (the last admin cannot be removed)
+#[spacetimedb::reducer]
+pub fn revoke_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    if !is_admin(ctx, identity) {
+        return Err("Identity is not an admin".to_string());
+    }
+    if ctx.db.admin().count() <= 1 {
+        return Err("Cannot revoke the last admin".to_string());
+    }
+
+    ctx.db.admin().identity().delete(identity);
+
+    log::info!("Admin {} revoked admin from {}", ctx.sender, identity);
+    Ok(())
+}
+
+/// Add a crew template to the shop pool
+#[spacetimedb::reducer]
+#[allow(clippy::too_many_arguments)]
+pub fn admin_add_crew_template(
+    ctx: &ReducerContext,
+    name: String,
+    rarity: CrewRarity,
+    traits: Vec<CrewTrait>,
+    max_hp: u32,
+    ability_power: u32,
+    attack: u32,
+    attack_speed: f32,
+    defense: u32,
+    magic_resistance: u32,
+    cost: u32,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let template = CrewTemplate {
+        id: 0,
+        name: name.trim().to_string(),
+        rarity,
+        traits,
+        max_hp,
+        ability_power,
+        attack,
+        attack_speed,
+        defense,
+        magic_resistance,
+        cost,
+    };
+    validate_crew_template(&template)?;
+
+    if ctx.db.crew_template().iter().any(|t| t.name.eq_ignore_ascii_case(&template.name)) {
+        return Err("A crew template with this name already exists".to_string());
+    }
+
+    let template = ctx.db.crew_template().insert(template);
+
+    log::info!("Added crew template {} ({})", template.id, template.name);
+    Ok(())
+}
+
+/// Update a crew template, leaving fields that are None unchanged.
+/// `sync_policy` decides whether shop offers and owned crew of this template pick up the change.
+#[spacetimedb::reducer]
+#[allow(clippy::too_many_arguments)]
+pub fn admin_update_crew_template(
+    ctx: &ReducerContext,
+    template_id: u64,
+    name: Option<String>,
+    max_hp: Option<u32>,
+    attack: Option<u32>,
+    defense: Option<u32>,
+    cost: Option<u32>,
+    rarity: Option<CrewRarity>,
+    traits: Option<Vec<CrewTrait>>,
+    ability_power: Option<u32>,
+    attack_speed: Option<f32>,
+    magic_resistance: Option<u32>,
+    sync_policy: TemplateSyncPolicy,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let template = ctx.db.crew_template().id().find(template_id)
+        .ok_or("Crew template not found")?;
+
+    let template = CrewTemplate {
+        name: name.map(|n| n.trim().to_string()).unwrap_or(template.name),
+        rarity: rarity.unwrap_or(template.rarity),
+        traits: traits.unwrap_or(template.traits),
+        max_hp: max_hp.unwrap_or(template.max_hp),
+        ability_power: ability_power.unwrap_or(template.ability_power),
+        attack: attack.unwrap_or(template.attack),
+        attack_speed: attack_speed.unwrap_or(template.attack_speed),
+        defense: defense.unwrap_or(template.defense),
+        magic_resistance: magic_resistance.unwrap_or(template.magic_resistance),
+        cost: cost.unwrap_or(template.cost),
+        ..template
+    };
+    validate_crew_template(&template)?;
+
+    if ctx.db.crew_template().iter()
+        .any(|t| t.id != template_id && t.name.eq_ignore_ascii_case(&template.name)) {
+        return Err("A crew template with this name already exists".to_string());
+    }
+
+    let template = ctx.db.crew_template().id().update(template);
+    resync_crew_template(ctx, &template, sync_policy);
+
+    log::info!("Updated crew template {} ({}), sync policy {:?}", template.id, template.name, sync_policy);
+    Ok(())
+}
+
+/// Delete a crew template. Open shop offers of it are removed, owned crew are kept.
+#[spacetimedb::reducer]
+pub fn admin_delete_crew_template(ctx: &ReducerContext, template_id: u64) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    if ctx.db.crew_template().id().find(template_id).is_none() {
+        return Err("Crew template not found".to_string());
+    }
+    if ctx.db.crew_template().count() <= 1 {
+        return Err("Cannot delete the last crew template".to_string());
+    }
+
+    ctx.db.crew_template().id().delete(template_id);
+    let removed_offers = ctx.db.shop_crew().template_id().delete(template_id);
+
+    log::info!("Deleted crew template {} and {} shop offers", template_id, removed_offers);
+    Ok(())
+}
+
+/// Re-import the embedded crew roster file into an existing database, matching templates by name.
+/// New names are added, known names are overwritten, templates missing from the file are kept.
+#[spacetimedb::reducer]
+pub fn admin_reimport_crew_templates(ctx: &ReducerContext, sync_policy: TemplateSyncPolicy) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let templates = crew_templates()?;
+    let (added, updated) = import_crew_templates(ctx, templates, sync_policy);
+
+    log::info!("Re-imported crew templates: {} added, {} updated, sync policy {:?}", added, updated, sync_policy);
+    Ok(())
+}
+
+/// Apply pending data migrations, run once after publishing an update
+#[spacetimedb::reducer]
+pub fn admin_run_migrations(ctx: &ReducerContext) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    run_migrations(ctx);
+    Ok(())
+}
+
+/// Set the XP needed to reach a player level from the level below
+#[spacetimedb::reducer]
+pub fn admin_update_level_threshold(ctx: &ReducerContext, level: u8, xp_required: u8) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    if level < 2 {
+        return Err("Players start at level 1, thresholds begin at level 2".to_string());
+    }
+    if xp_required == 0 {
+        return Err("XP required must be at least 1".to_string());
+    }
+
+    let threshold = LevelThreshold { level, xp_required };
+    if ctx.db.level_threshold().level().find(level).is_some() {
+        ctx.db.level_threshold().level().update(threshold);
+    } else {
+        // Levels are reached one after another, a gap would end levelling before it
+        if level > 2 && ctx.db.level_threshold().level().find(level - 1).is_none() {
+            return Err(format!("Level {} needs a threshold before level {} can be added", level - 1, level));
+        }
+        ctx.db.level_threshold().insert(threshold);
+    }
+
+    log::info!("Level {} now requires {} XP", level, xp_required);
+    Ok(())
+}
+
+/// Set the shop rarity odds (in percent, summing to 100) of a player level
+#[spacetimedb::reducer]
+pub fn admin_update_shop_odds(
+    ctx: &ReducerContext,
+    level: u8,
+    common: u32,
+    uncommon: u32,
+    rare: u32,
+    epic: u32,
+    legendary: u32,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    if level == 0 {
+        return Err("Player levels start at 1".to_string());
+    }
+
+    let odds = ShopOdds { level, common, uncommon, rare, epic, legendary };
+    if odds.total() != 100 {
+        return Err("Odds must add up to 100".to_string());
+    }
+
+    if ctx.db.shop_odds().level().find(level).is_some() {
+        ctx.db.shop_odds().level().update(odds);
     } else {
-        battle.player1
+        ctx.db.shop_odds().insert(odds);
+    }
+
+    log::info!("Updated shop odds for level {}: {}/{}/{}/{}/{}", level, common, uncommon, rare, epic, legendary);
+    Ok(())
+}
+
+/// Update the tunable game rules, leaving fields that are None unchanged
+#[spacetimedb::reducer]
+pub fn admin_update_game_config(ctx: &ReducerContext, reroll_cost: Option<u32>) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let config = game_config(ctx);
+    let config = GameConfig {
+        reroll_cost: reroll_cost.unwrap_or(config.reroll_cost),
+        ..config
     };
 
-    // Get winner and loser
-    let mut winner_player = ctx.db.player().identity().find(&winner)
-        .ok_or("Winner not found")?;
-    let mut loser_player = ctx.db.player().identity().find(&loser)
-        .ok_or("Loser not found")?;
+    if ctx.db.game_config().id().find(0).is_some() {
+        ctx.db.game_config().id().update(config);
+    } else {
+        ctx.db.game_config().insert(config);
+    }
+
+    log::info!("Updated game config");
+    Ok(())
+}
 
-    // Calculate bounty reward (loser's bounty goes to winner)
-    let bounty_reward = loser_player.bounty;
+/// Add an item combination recipe
+#[spacetimedb::reducer]
+pub fn admin_add_recipe(ctx: &ReducerContext, component1: ItemComponent, component2: ItemComponent, result: CompletedItem) -> Result<(), String> {
+    require_admin(ctx)?;
 
-    // Update winner
-    winner_player.wins += 1;
-    winner_player.bounty += 100_000; // +100k per win
-    winner_player.berries += bounty_reward; // Claim loser's bounty
+    if ctx.db.item_combination_recipe().iter().any(|r| recipe_matches(&r, component1, component2)) {
+        return Err("A recipe for these components already exists".to_string());
+    }
 
-    ctx.db.player().identity().update(winner_player);
+    ctx.db.item_combination_recipe().insert(ItemCombinationRecipe {
+        id: 0,
+        component1,
+        component2,
+        result,
+    });
 
-    // Update loser
-    loser_player.losses += 1;
-    loser_player.bounty = 0; // Reset bounty to 0 on loss
+    log::info!("Added recipe {:?} + {:?} -> {:?}", component1, component2, result);
+    Ok(())
+}
 
-    ctx.db.player().identity().update(loser_player);
+/// Update an item combination recipe, leaving fields that are None unchanged
+#[spacetimedb::reducer]
+pub fn admin_update_recipe(
+    ctx: &ReducerContext,
+    recipe_id: u64,
+    component1: Option<ItemComponent>,
+    component2: Option<ItemComponent>,
+    result: Option<CompletedItem>,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let recipe = ctx.db.item_combination_recipe().id().find(recipe_id)
+        .ok_or("Recipe not found")?;
+
+    let component1 = component1.unwrap_or(recipe.component1);
+    let component2 = component2.unwrap_or(recipe.component2);
+
+    if ctx.db.item_combination_recipe().iter()
+        .any(|r| r.id != recipe_id && recipe_matches(&r, component1, component2)) {
+        return Err("A recipe for these components already exists".to_string());
+    }
 
-    // Update battle with bounty reward
-    ctx.db.battle().id().update(Battle {
-        bounty_reward,
-        ..battle
+    let recipe = ctx.db.item_combination_recipe().id().update(ItemCombinationRecipe {
+        component1,
+        component2,
+        result: result.unwrap_or(recipe.result),
+        ..recipe
     });
 
-    log::info!(
-        "Battle {} completed: Winner {} claimed {} bounty from {}",
-        battle_id,
-        winner,
-        bounty_reward,
-        loser
-    );
+    log::info!("Updated recipe {}: {:?} + {:?} -> {:?}", recipe_id, recipe.component1, recipe.component2, recipe.result);
+    Ok(())
+}
+
+/// Delete an item combination recipe
+#[spacetimedb::reducer]
+pub fn admin_delete_recipe(ctx: &ReducerContext, recipe_id: u64) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    if !ctx.db.item_combination_recipe().id().delete(recipe_id) {
+        return Err("Recipe not found".to_string());
+    }
+
+    log::info!("Deleted recipe {}", recipe_id);
+    Ok(())
+}
+
+fn validate_chance(value: f32, field: &str) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&value) {
+        return Err(format!("{} must be between 0 and 1", field));
+    }
+    Ok(())
+}
+
+/// Tune the stats of an item component, leaving fields that are None unchanged
+#[spacetimedb::reducer]
+#[allow(clippy::too_many_arguments)]
+pub fn admin_update_item_component_stats(
+    ctx: &ReducerContext,
+    component: ItemComponent,
+    name: Option<String>,
+    description: Option<String>,
+    bonus_ad: Option<i32>,
+    bonus_crit_chance: Option<f32>,
+    bonus_attack_speed: Option<f32>,
+    bonus_ap: Option<i32>,
+    bonus_armor: Option<i32>,
+    bonus_mr: Option<i32>,
+    bonus_mana: Option<i32>,
+    bonus_hp: Option<i32>,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    let stats = ctx.db.item_component_stats().component().find(component)
+        .ok_or("Item component stats not found")?;
+
+    let stats = ItemComponentStats {
+        name: name.unwrap_or(stats.name),
+        description: description.unwrap_or(stats.description),
+        bonus_ad: bonus_ad.unwrap_or(stats.bonus_ad),
+        bonus_crit_chance: bonus_crit_chance.unwrap_or(stats.bonus_crit_chance),
+        bonus_attack_speed: bonus_attack_speed.unwrap_or(stats.bonus_attack_speed),
+        bonus_ap: bonus_ap.unwrap_or(stats.bonus_ap),
+        bonus_armor: bonus_armor.unwrap_or(stats.bonus_armor),
+        bonus_mr: bonus_mr.unwrap_or(stats.bonus_mr),
+        bonus_mana: bonus_mana.unwrap_or(stats.bonus_mana),
+        bonus_hp: bonus_hp.unwrap_or(stats.bonus_hp),
+        ..stats
+    };
+
+    if stats.name.trim().is_empty() {
+        return Err("Name cannot be empty".to_string());
+    }
+    validate_chance(stats.bonus_crit_chance, "Crit chance")?;
+    if stats.bonus_attack_speed <= -1.0 {
+        return Err("Attack speed bonus must be above -100%".to_string());
+    }
+
+    ctx.db.item_component_stats().component().update(stats);
+    resync_equipped_effective_stats(ctx);
+
+    log::info!("Updated item component stats for {:?}", component);
+    Ok(())
+}
+
+/// Tune the stats and proc of a completed item, leaving fields that are None unchanged
+#[spacetimedb::reducer]
+#[allow(clippy::too_many_arguments)]
+pub fn admin_update_completed_item_stats(
+    ctx: &ReducerContext,
+    item: CompletedItem,
+    name: Option<String>,
+    description: Option<String>,
+    bonus_ad: Option<i32>,
+    bonus_crit_chance: Option<f32>,
+    bonus_crit_damage: Option<f32>,
+    bonus_attack_speed: Option<f32>,
+    bonus_ap: Option<i32>,
+    bonus_armor: Option<i32>,
+    bonus_mr: Option<i32>,
+    bonus_mana: Option<i32>,
+    bonus_hp: Option<i32>,
+    bonus_hp_regen: Option<f32>,
+    has_splash: Option<bool>,
+    armor_shred: Option<i32>,
+    proc_every: Option<u32>,
+    proc_chance: Option<f32>,
+    proc_damage: Option<u32>,
+    proc_multiplier: Option<f32>,
+    proc_shots: Option<u32>,
+    proc_stun_seconds: Option<f32>,
+) -> Result<(), String> {
+    require_admin(ctx)?;
+
+    // Items without an every-Nth-attack proc keep 0 here, so only an explicit 0 is rejected
+    if proc_every == Some(0) {
+        return Err("Proc interval must be at least 1 attack".to_string());
+    }
+
+    let stats = ctx.db.completed_item_stats().item().find(item)
+        .ok_or("Completed item stats not found")?;
+
+    let stats = CompletedItemStats {
+        name: name.unwrap_or(stats.name),
+        description: description.unwrap_or(stats.description),
+        bonus_ad: bonus_ad.unwrap_or(stats.bonus_ad),
+        bonus_crit_chance: bonus_crit_chance.unwrap_or(stats.bonus_crit_chance),
+        bonus_crit_damage: bonus_crit_damage.unwrap_or(stats.bonus_crit_damage),
+        bonus_attack_speed: bonus_attack_speed.unwrap_or(stats.bonus_attack_speed),
+        bonus_ap: bonus_ap.unwrap_or(stats.bonus_ap),
+        bonus_armor: bonus_armor.unwrap_or(stats.bonus_armor),
+        bonus_mr: bonus_mr.unwrap_or(stats.bonus_mr),
+        bonus_mana: bonus_mana.unwrap_or(stats.bonus_mana),
+        bonus_hp: bonus_hp.unwrap_or(stats.bonus_hp),
+        bonus_hp_regen: bonus_hp_regen.unwrap_or(stats.bonus_hp_regen),
+        has_splash: has_splash.unwrap_or(stats.has_splash),
+        armor_shred: armor_shred.unwrap_or(stats.armor_shred),
+        proc_every: proc_every.unwrap_or(stats.proc_every),
+        proc_chance: proc_chance.unwrap_or(stats.proc_chance),
+        proc_damage: proc_damage.unwrap_or(stats.proc_damage),
+        proc_multiplier: proc_multiplier.unwrap_or(stats.proc_multiplier),
+        proc_shots: proc_shots.unwrap_or(stats.proc_shots),
+        proc_stun_seconds: proc_stun_seconds.unwrap_or(stats.proc_stun_seconds),
+        ..stats
+    };
+
+    if stats.name.trim().is_empty() {
+        return Err("Name cannot be empty".to_string());
+    }
+    validate_chance(stats.bonus_crit_chance, "Crit chance")?;
+    validate_chance(stats.proc_chance, "Proc chance")?;
+    if stats.bonus_attack_speed <= -1.0 {
+        return Err("Attack speed bonus must be above -100%".to_string());
+    }
+    if stats.armor_shred < 0 || stats.bonus_hp_regen < 0.0 || stats.proc_stun_seconds < 0.0 {
+        return Err("Armor shred, HP regen and stun duration cannot be negative".to_string());
+    }
+    if stats.proc_multiplier <= 0.0 || stats.proc_shots == 0 {
+        return Err("Proc multiplier and shots must be positive".to_string());
+    }
+
+    ctx.db.completed_item_stats().item().update(stats);
+    resync_equipped_effective_stats(ctx);
+
+    log::info!("Updated completed item stats for {:?}", item);
     Ok(())
 }
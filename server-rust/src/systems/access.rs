@@ -0,0 +1,32 @@
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp, log};
+use crate::tables::*;
+
+/// The admin row for the first identity to reach a database without admins, if there are none yet
+pub fn bootstrap_admin(admin_count: u64, identity: Identity, granted_at: Timestamp) -> Option<Admin> {
+    (admin_count == 0).then_some(Admin {
+        identity,
+        granted_by: identity,
+        granted_at,
+    })
+}
+
+/// Make the sender the first admin - only if no admin exists yet.
+/// Runs at init for the publisher, and on connect because an upgraded database never runs init.
+pub fn init_admin(ctx: &ReducerContext) {
+    if let Some(admin) = bootstrap_admin(ctx.db.admin().count(), ctx.sender, ctx.timestamp) {
+        ctx.db.admin().insert(admin);
+        log::info!("Bootstrapped admin {}", ctx.sender);
+    }
+}
+
+pub fn is_admin(ctx: &ReducerContext, identity: Identity) -> bool {
+    ctx.db.admin().identity().find(identity).is_some()
+}
+
+/// Guard for every admin_* reducer: errors unless the caller is an admin
+pub fn require_admin(ctx: &ReducerContext) -> Result<(), String> {
+    if !is_admin(ctx, ctx.sender) {
+        return Err("Only admins can perform this action".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,358 @@
+use spacetimedb::{Identity, ReducerContext, rand::Rng, Table, TimeDuration, log};
+use crate::types::*;
+use crate::tables::*;
+use crate::systems::elimination::*;
+use crate::systems::item_stats::*;
+use crate::systems::leveling::*;
+use crate::systems::pool::*;
+use battle_core::{BattleRng, BattleSim, CombatUnit};
+
+/// Spawn the units of both players and schedule the battle tick for a battle that just went InProgress
+pub fn begin_battle_simulation(ctx: &ReducerContext, battle: &Battle) {
+    let catalog = ItemCatalog::load(ctx);
+    spawn_battle_units(ctx, battle.id, battle.player1, 0, &catalog);
+    if let Some(player2) = battle.player2 {
+        spawn_battle_units(ctx, battle.id, player2, 1, &catalog);
+    }
+
+    ctx.db.battle_tick_timer().insert(BattleTickTimer {
+        scheduled_id: 0,
+        scheduled_at: TimeDuration::from_micros(1_000_000 / BATTLE_TICK_RATE as i64).into(),
+        battle_id: battle.id,
+    });
+
+    log::info!("Battle {} started", battle.id);
+}
+
+/// Snapshot every crew member the player has on the field into the battle, up to their level's field cap.
+/// The battle only reads these rows from here on, so roster edits made mid-battle don't affect it.
+fn spawn_battle_units(ctx: &ReducerContext, battle_id: u64, owner: Identity, side: u8, catalog: &ItemCatalog) {
+    let Some(player) = ctx.db.player().identity().find(owner) else {
+        return;
+    };
+    let crews: Vec<Crew> = ctx.db.crew().owner().filter(&owner).collect();
+
+    for crew in field_lineup(&crews, max_field_crew(&player)) {
+        if let Some(unit) = snapshot_crew(crew, battle_id, side, catalog) {
+            ctx.db.battle_unit().insert(unit);
+        }
+    }
+}
+
+/// Build the combat state of a fielded crew member from its effective stats.
+/// Returns None for crew sitting on the bench.
+pub fn snapshot_crew(crew: &Crew, battle_id: u64, side: u8, catalog: &ItemCatalog) -> Option<BattleUnit> {
+    let slot = crew.slot_index?;
+
+    let stats = compute_effective_stats(crew, catalog);
+    let bonus = equipped_bonus(crew, catalog);
+
+    Some(BattleUnit {
+        id: 0,
+        battle_id,
+        crew_id: crew.id,
+        owner: crew.owner,
+        side,
+        rarity: crew.rarity,
+        level: crew.level,
+        position: slot_position(slot, side),
+        velocity: DbVector2::new(0.0, 0.0),
+        radius: UNIT_RADIUS,
+        max_hp: stats.max_hp,
+        current_hp: stats.max_hp,
+        attack: stats.attack,
+        defense: stats.defense,
+        ability_power: stats.ability_power,
+        magic_resist: stats.magic_resistance,
+        attack_speed: stats.attack_speed,
+        attack_range: stats.attack_range,
+        crit_chance: stats.crit_chance,
+        crit_damage: stats.crit_damage,
+        max_mana: UNIT_MAX_MANA,
+        current_mana: stats.starting_mana,
+        mana_per_attack: UNIT_MANA_PER_ATTACK,
+        attack_cooldown: 0.0,
+        target_unit_id: None,
+        ability_ready: false,
+        ability_cooldown: 0.0,
+        is_stunned: false,
+        stun_duration: 0.0,
+        hp_regen: stats.hp_regen,
+        armor_shred: bonus.armor_shred.max(0) as u32,
+        has_splash: bonus.has_splash,
+        attack_count: 0,
+        items: equipped_items(crew).collect(),
+    })
+}
+
+/// Starting position of a field slot. Player1 deploys on the bottom half, player2 on the top half.
+fn slot_position(slot: u8, side: u8) -> DbVector2 {
+    let column = (slot % FIELD_COLUMNS) as f32;
+    let row = (slot / FIELD_COLUMNS) as f32;
+    let x = BATTLE_ARENA_SIZE * 0.25 + column * BATTLE_ARENA_SIZE * 0.125;
+    let y = if side == 0 {
+        BATTLE_ARENA_SIZE * 0.625 + row * BATTLE_ARENA_SIZE * 0.125
+    } else {
+        BATTLE_ARENA_SIZE * 0.375 - row * BATTLE_ARENA_SIZE * 0.125
+    };
+    DbVector2::new(x, y)
+}
+
+/// Advance a battle by one tick and finish it once a side has been wiped out or time runs out
+pub fn run_battle_tick(ctx: &ReducerContext, battle_id: u64) {
+    let Some(battle) = ctx.db.battle().id().find(battle_id) else {
+        stop_battle_timer(ctx, battle_id);
+        return;
+    };
+
+    if battle.status != BattleStatus::InProgress {
+        stop_battle_timer(ctx, battle_id);
+        return;
+    }
+
+    // Sorted by id so every tick iterates units in the same order the simulation was seeded with
+    let mut rows: Vec<BattleUnit> = ctx.db.battle_unit().battle_id().filter(battle_id).collect();
+    rows.sort_by_key(|row| row.id);
+
+    let catalog = ItemCatalog::load(ctx);
+    let mut sim = BattleSim::new(
+        rows.iter().map(|row| to_combat_unit(row, &catalog)).collect(),
+        BattleRng::new(battle.rng_state),
+        battle.turn,
+    );
+    sim.step();
+
+    for (row, unit) in rows.into_iter().zip(&sim.units) {
+        ctx.db.battle_unit().id().update(apply_combat_unit(row, unit));
+    }
+
+    let battle = Battle {
+        turn: sim.tick,
+        rng_state: sim.rng.state(),
+        ..battle
+    };
+    match sim.winning_side() {
+        Some(side) => finish_battle(ctx, battle, side),
+        None => {
+            ctx.db.battle().id().update(battle);
+        }
+    }
+}
+
+pub fn to_combat_unit(row: &BattleUnit, catalog: &ItemCatalog) -> CombatUnit {
+    CombatUnit {
+        id: row.id,
+        side: row.side,
+        position: row.position.into(),
+        velocity: row.velocity.into(),
+        radius: row.radius,
+        max_hp: row.max_hp,
+        current_hp: row.current_hp,
+        attack: row.attack,
+        defense: row.defense,
+        ability_power: row.ability_power,
+        magic_resist: row.magic_resist,
+        attack_speed: row.attack_speed,
+        attack_range: row.attack_range,
+        crit_chance: row.crit_chance,
+        crit_damage: row.crit_damage,
+        max_mana: row.max_mana,
+        current_mana: row.current_mana,
+        mana_per_attack: row.mana_per_attack,
+        attack_cooldown: row.attack_cooldown,
+        target_unit_id: row.target_unit_id,
+        ability_ready: row.ability_ready,
+        ability_cooldown: row.ability_cooldown,
+        is_stunned: row.is_stunned,
+        stun_duration: row.stun_duration,
+        hp_regen: row.hp_regen,
+        armor_shred: row.armor_shred,
+        has_splash: row.has_splash,
+        attack_count: row.attack_count,
+        items: row
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Completed(completed) => catalog.effect(*completed),
+                Item::Component(_) => None,
+            })
+            .collect(),
+    }
+}
+
+/// Copy the simulated state back onto the row, keeping its battle/crew/owner links
+pub fn apply_combat_unit(row: BattleUnit, unit: &CombatUnit) -> BattleUnit {
+    BattleUnit {
+        position: unit.position.into(),
+        velocity: unit.velocity.into(),
+        current_hp: unit.current_hp,
+        defense: unit.defense,
+        current_mana: unit.current_mana,
+        attack_cooldown: unit.attack_cooldown,
+        target_unit_id: unit.target_unit_id,
+        ability_ready: unit.ability_ready,
+        ability_cooldown: unit.ability_cooldown,
+        is_stunned: unit.is_stunned,
+        stun_duration: unit.stun_duration,
+        attack_count: unit.attack_count,
+        ..row
+    }
+}
+
+fn finish_battle(ctx: &ReducerContext, battle: Battle, winning_side: u8) {
+    let winner = if winning_side == 0 { Some(battle.player1) } else { battle.player2 };
+    let battle_id = battle.id;
+    let turn = battle.turn;
+
+    ctx.db.battle().id().update(Battle {
+        winner,
+        status: BattleStatus::Finished,
+        ..battle
+    });
+    stop_battle_timer(ctx, battle_id);
+
+    log::info!("Battle {} finished after {} ticks, winning side {}", battle_id, turn, winning_side);
+}
+
+/// Remove the combat state of a battle once its result has been claimed
+pub fn clear_battle_units(ctx: &ReducerContext, battle_id: u64) {
+    ctx.db.battle_unit().battle_id().delete(battle_id);
+}
+
+fn stop_battle_timer(ctx: &ReducerContext, battle_id: u64) {
+    ctx.db.battle_tick_timer().battle_id().delete(battle_id);
+}
+
+/// Create a battle between two players of a lobby's round and start simulating it right away.
+/// With `ghost` set, player2's board is a copy fighting in place of a missing opponent.
+pub fn create_battle(ctx: &ReducerContext, player1: &Player, player2: &Player, lobby_id: u64, round: u32, ghost: bool) -> Battle {
+    let seed: u64 = ctx.rng().gen();
+    let battle = ctx.db.battle().insert(Battle {
+        id: 0,
+        player1: player1.identity,
+        player2: Some(player2.identity),
+        winner: None,
+        status: BattleStatus::InProgress,
+        turn: 0,
+        bounty_reward: 0,
+        player1_bounty: player1.bounty,
+        player2_bounty: player2.bounty,
+        seed,
+        rng_state: seed,
+        lobby_id,
+        round,
+        settled: false,
+        ghost,
+    });
+
+    begin_battle_simulation(ctx, &battle);
+    battle
+}
+
+/// Play out the rest of a battle instantly, for fights still running when the combat phase ends
+pub fn force_finish_battle(ctx: &ReducerContext, battle: Battle) {
+    let mut rows: Vec<BattleUnit> = ctx.db.battle_unit().battle_id().filter(battle.id).collect();
+    rows.sort_by_key(|row| row.id);
+
+    let catalog = ItemCatalog::load(ctx);
+    let mut sim = BattleSim::new(
+        rows.iter().map(|row| to_combat_unit(row, &catalog)).collect(),
+        BattleRng::new(battle.rng_state),
+        battle.turn,
+    );
+    let winning_side = sim.run();
+
+    for (row, unit) in rows.into_iter().zip(&sim.units) {
+        ctx.db.battle_unit().id().update(apply_combat_unit(row, unit));
+    }
+
+    let battle = Battle {
+        turn: sim.tick,
+        rng_state: sim.rng.state(),
+        ..battle
+    };
+    finish_battle(ctx, battle, winning_side);
+}
+
+/// Pay out a finished battle: the winner claims the loser's bounty and both records are updated.
+/// A ghost board only stands in for its owner, so only player1's record changes in a ghost battle.
+pub fn settle_battle(ctx: &ReducerContext, battle: Battle) -> Result<(), String> {
+    let battle_id = battle.id;
+    let winner = battle.winner.ok_or("Battle has no winner")?;
+    let loser = if winner == battle.player1 {
+        battle.player2.ok_or("Battle has no player2")?
+    } else {
+        battle.player1
+    };
+
+    // Players who left the lobby since are out of its battles
+    let takes_part = |identity: Identity| {
+        (!battle.ghost || identity == battle.player1) && player_lobby_id(ctx, identity) == battle.lobby_id
+    };
+    let (winner_counts, loser_counts) = (takes_part(winner), takes_part(loser));
+
+    // Get winner and loser
+    let mut winner_player = ctx.db.player().identity().find(winner)
+        .ok_or("Winner not found")?;
+    let mut loser_player = ctx.db.player().identity().find(loser)
+        .ok_or("Loser not found")?;
+
+    // Calculate bounty reward (loser's bounty goes to winner)
+    let bounty_reward = if winner_counts && loser_counts { loser_player.bounty } else { 0 };
+
+    // Every enemy unit still standing hurts the loser on top of the round damage
+    let winning_side = if winner == battle.player1 { 0 } else { 1 };
+    let survivors: Vec<(CrewRarity, u8)> = ctx.db.battle_unit().battle_id().filter(battle_id)
+        .filter(|u| u.side == winning_side && u.current_hp > 0)
+        .map(|u| (u.rarity, u.level))
+        .collect();
+    let damage = battle_damage(battle.round, &survivors);
+
+    // Update winner
+    if winner_counts {
+        winner_player.wins += 1;
+        winner_player.win_streak += 1;
+        winner_player.loss_streak = 0;
+        winner_player.bounty = winner_player.bounty.saturating_add(100_000); // +100k per win
+        winner_player.berries = winner_player.berries.saturating_add(bounty_reward); // Claim loser's bounty
+
+        ctx.db.player().identity().update(winner_player);
+    }
+
+    // Update loser
+    let mut knocked_out = false;
+    if loser_counts {
+        loser_player.losses += 1;
+        loser_player.loss_streak += 1;
+        loser_player.win_streak = 0;
+        loser_player.bounty = 0; // Reset bounty to 0 on loss
+        loser_player.hp = loser_player.hp.saturating_sub(damage);
+        knocked_out = loser_player.hp == 0 && !loser_player.eliminated;
+
+        ctx.db.player().identity().update(loser_player);
+    }
+
+    // Update battle with bounty reward
+    ctx.db.battle().id().update(Battle {
+        bounty_reward,
+        settled: true,
+        ..battle
+    });
+
+    // The fight is over, its unit snapshot is no longer needed
+    clear_battle_units(ctx, battle_id);
+
+    if knocked_out {
+        eliminate_player(ctx, loser)?;
+    }
+
+    log::info!(
+        "Battle {} completed: Winner {} claimed {} bounty from {}, who lost {} HP",
+        battle_id,
+        winner,
+        bounty_reward,
+        loser,
+        damage
+    );
+    Ok(())
+}
@@ -1,126 +1,205 @@
-use spacetimedb::{ReducerContext, Table, log};
-use CrewRarity::*;
-use CrewTrait::*;
+use spacetimedb::{Identity, ReducerContext, Table, log};
+use serde::Deserialize;
 use crate::types::*;
-use crate::tables::{CrewTemplate, crew_template};
+use crate::tables::*;
+use crate::systems::item_stats::sync_effective_stats;
 
-/// Helper macro to create crew templates with less boilerplate
-/// Default values: ability_power=10, attack_speed=1.0, magic_resistance=5
-macro_rules! crew {
-    ($name:expr, $rarity:expr, [$($trait:expr),*], $hp:expr, $atk:expr, $def:expr, $cost:expr) => {
-        CrewTemplate {
+/// Crew roster shipped with the module, also loaded by the offline battle simulator
+pub const CREW_TEMPLATES_JSON: &str = include_str!("../../data/crew_templates.json");
+
+/// The crew roster data file. Its "note" says which values are still placeholders and is not loaded.
+#[derive(Deserialize)]
+struct CrewRosterData {
+    templates: Vec<CrewTemplateData>,
+}
+
+/// One entry of the crew roster data file
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CrewTemplateData {
+    name: String,
+    rarity: CrewRarity,
+    traits: Vec<CrewTrait>,
+    max_hp: u32,
+    ability_power: u32,
+    attack: u32,
+    attack_speed: f32,
+    defense: u32,
+    magic_resistance: u32,
+    cost: u32,
+}
+
+/// Parse and validate a crew roster file. Names must be unique, they are the re-import key.
+pub fn parse_crew_templates(json: &str) -> Result<Vec<CrewTemplate>, String> {
+    let entries = serde_json::from_str::<CrewRosterData>(json)
+        .map_err(|e| format!("Invalid crew template data: {}", e))?
+        .templates;
+
+    let mut templates: Vec<CrewTemplate> = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let template = CrewTemplate {
             id: 0,
-            name: $name.to_string(),
-            rarity: $rarity,
-            traits: vec![$($trait),*],
-            max_hp: $hp,
-            ability_power: 10,      // Default AP
-            attack: $atk,
-            attack_speed: 1.0,      // Default attack speed
-            defense: $def,
-            magic_resistance: 5,    // Default MR
-            cost: $cost,
+            name: entry.name,
+            rarity: entry.rarity,
+            traits: entry.traits,
+            max_hp: entry.max_hp,
+            ability_power: entry.ability_power,
+            attack: entry.attack,
+            attack_speed: entry.attack_speed,
+            defense: entry.defense,
+            magic_resistance: entry.magic_resistance,
+            cost: entry.cost,
+        };
+        validate_crew_template(&template).map_err(|e| format!("{}: {}", template.name, e))?;
+        if templates.iter().any(|t| t.name.eq_ignore_ascii_case(&template.name)) {
+            return Err(format!("Duplicate crew template: {}", template.name));
         }
-    };
+        templates.push(template);
+    }
+
+    Ok(templates)
 }
 
 /// Initialize the crew template database - called once on server initialization
-pub fn init_crew_templates(ctx: &ReducerContext) {
+pub fn init_crew_templates(ctx: &ReducerContext) -> Result<(), String> {
     // Only initialize if the table is empty
     if ctx.db.crew_template().count() > 0 {
         log::info!("Crew templates already initialized, skipping...");
-        return;
+        return Ok(());
     }
 
     log::info!("Initializing crew template database...");
 
-    let crew_templates = vec![
-        // Straw Hats
-        crew!("Brook", Uncommon, [StrawHat, Swordsman], 25, 2, 5, 100000),
-        crew!("Franky", Rare, [StrawHat, Brawler], 45, 3, 8, 300000),
-        crew!("Jimbei", Rare, [StrawHat, Brawler], 60, 3, 8, 400000),
-        crew!("Luffy", Legendary, [StrawHat, Emperor, Zoan], 40, 2, 12, 500000),
-        crew!("Nami", Uncommon, [StrawHat], 20, 1, 5, 100000),
-        crew!("Sanji", Rare, [StrawHat], 35, 3, 8, 300000),
-        crew!("Zoro", Rare, [StrawHat, Swordsman], 50, 4, 8, 400000),
-
-        // Red Hair Pirates
-        crew!("Beckman", Rare, [RedHairPirates, Sniper], 40, 5, 8, 400000),
-        crew!("Lucky Roux", Rare, [RedHairPirates, Brawler], 50, 4, 8, 300000),
-        crew!("Shanks", Uncommon, [RedHairPirates, Swordsman], 20, 3, 5, 200000),
-        crew!("Yasopp", Rare, [RedHairPirates, Sniper], 35, 4, 8, 300000),
-
-        // Whitebeard Pirates
-        crew!("Ace", Legendary, [WhitebearedPirates, Logia], 55, 5, 12, 500000),
-        crew!("Edward Newgate", Common, [WhitebearedPirates, Emperor], 100, 8, 10, 600000),
-        crew!("Jozu", Rare, [WhitebearedPirates, Paramecia], 70, 4, 8, 400000),
-        crew!("Marco", Legendary, [WhitebearedPirates, Zoan], 65, 4, 12, 500000),
-        crew!("Vista", Rare, [WhitebearedPirates, Swordsman], 50, 5, 8, 400000),
-
-        // Blackbeard Pirates
-        crew!("Blackbeard", Legendary, [BlackbearedPirates, Logia, Paramecia], 60, 5, 12, 500000),
-        crew!("Burgess", Uncommon, [BlackbearedPirates, Brawler], 35, 2, 5, 100000),
-        crew!("Devon", Rare, [BlackbearedPirates, Zoan], 40, 3, 8, 400000),
-        crew!("DocQ", Rare, [BlackbearedPirates, Paramecia], 30, 2, 8, 300000),
-        crew!("Kuzan", Legendary, [BlackbearedPirates, Logia], 55, 4, 12, 500000),
-        crew!("Laffitte", Rare, [BlackbearedPirates], 35, 3, 8, 300000),
-        crew!("Pizarro", Rare, [BlackbearedPirates], 50, 3, 8, 400000),
-        crew!("Shiryu", Rare, [BlackbearedPirates, Swordsman], 50, 4, 8, 400000),
-        crew!("Shot", Rare, [BlackbearedPirates], 40, 3, 8, 300000),
-        crew!("VanAugur", Rare, [BlackbearedPirates, Sniper], 30, 3, 8, 400000),
-        crew!("Wolf", Rare, [BlackbearedPirates, Giants], 75, 4, 9, 400000),
-
-        // Big Mom Pirates
-        crew!("Charlotte Linlin", Common, [BigMomPirates, Emperor], 110, 9, 11, 600000),
-        crew!("Cracker", Rare, [BigMomPirates, Swordsman, Paramecia], 45, 5, 8, 400000),
-        crew!("Katakuri", Common, [BigMomPirates, Paramecia], 80, 7, 8, 600000),
-        crew!("Perospero", Rare, [BigMomPirates, Paramecia], 45, 4, 8, 400000),
-        crew!("Smoothie", Legendary, [BigMomPirates, Swordsman], 55, 5, 12, 500000),
-
-        // Heart Pirates
-        crew!("Bepo", Uncommon, [HeartPirates, Brawler], 30, 2, 5, 100000),
-        crew!("Jean Bart", Rare, [HeartPirates, Swordsman], 40, 3, 8, 200000),
-        crew!("Law", Legendary, [HeartPirates, Swordsman, Paramecia], 60, 5, 12, 500000),
-        crew!("Penguin", Uncommon, [HeartPirates], 25, 2, 5, 100000),
-
-        // Cross Guild
-        crew!("Buggy", Uncommon, [CrossGuildPirates, Paramecia], 30, 2, 5, 100000),
-        crew!("Crocodile", Rare, [CrossGuildPirates, Logia], 45, 5, 8, 400000),
-        crew!("Mihawk", Legendary, [CrossGuildPirates, Swordsman], 60, 6, 12, 500000),
-
-        // Revolutionary Army
-        crew!("Dragon", Legendary, [Revolutionary], 55, 4, 12, 500000),
-        crew!("Sabo", Rare, [Revolutionary, Logia], 45, 3, 8, 400000),
-        crew!("Emporio Ivankov", Common, [Revolutionary, Brawler], 74, 12, 10, 100000),
-        crew!("Bartholomew Kuma", Rare, [Revolutionary, Paramecia, Brawler], 80, 22, 18, 300000),
-
-        // Giants
-        crew!("Brogy", Rare, [Giants, Brawler], 38, 3, 8, 300000),
-        crew!("Dorry", Rare, [Giants, Swordsman], 35, 4, 8, 300000),
-        crew!("Harald", Legendary, [Giants], 80, 5, 13, 500000),
-        crew!("Loki", Rare, [Giants], 70, 4, 8, 400000),
-        crew!("Hajrudin", Uncommon, [Giants], 30, 4, 8, 100000),
-
-        // Gorosei (Five Elders)
-        crew!("JuPeter", Legendary, [Gorosei, Zoan], 75, 6, 12, 500000),
-        crew!("Mars", Legendary, [Gorosei, Zoan], 65, 7, 12, 500000),
-        crew!("Nusjuro", Legendary, [Gorosei, Zoan, Swordsman], 60, 9, 12, 500000),
-        crew!("Saturn", Legendary, [Gorosei, Zoan], 70, 6, 12, 500000),
-        crew!("Warcury", Legendary, [Gorosei, Zoan], 80, 8, 13, 500000),
-
-        // Holy Knights
-        crew!("Figarland Garling", Legendary, [HolyKnights], 96, 26, 15, 50000),
-        crew!("Figarland Shamrock", Epic, [HolyKnights], 86, 22, 12, 50000),
-        crew!("Hanmayer Gunko", Rare, [HolyKnights], 73, 18, 10, 300000),
-        crew!("Shepherd Sommers", Rare, [HolyKnights], 75, 19, 9, 300000),
-        crew!("Rimoshifu Kiilingham", Rare, [HolyKnights], 71, 17, 9, 300000),
-        crew!("Satcheis Maffey", Rare, [HolyKnights], 69, 16, 11, 300000),
-    ];
-
     // Insert all crew templates into the database
-    for template in crew_templates {
+    for template in crew_templates()? {
         ctx.db.crew_template().insert(template);
     }
 
     log::info!("Crew template database initialized with {} templates", ctx.db.crew_template().count());
+    Ok(())
+}
+
+/// Upsert templates by name, keeping the ids of existing ones. Returns (added, updated).
+pub fn import_crew_templates(ctx: &ReducerContext, templates: Vec<CrewTemplate>, policy: TemplateSyncPolicy) -> (u32, u32) {
+    let existing: Vec<CrewTemplate> = ctx.db.crew_template().iter().collect();
+    let (mut added, mut updated) = (0, 0);
+
+    for template in templates {
+        match existing.iter().find(|t| t.name.eq_ignore_ascii_case(&template.name)) {
+            Some(current) => {
+                let template = ctx.db.crew_template().id().update(CrewTemplate { id: current.id, ..template });
+                resync_crew_template(ctx, &template, policy);
+                updated += 1;
+            }
+            None => {
+                ctx.db.crew_template().insert(template);
+                added += 1;
+            }
+        }
+    }
+
+    (added, updated)
+}
+
+/// Create a crew member from a template with stats scaled to the given level
+pub fn crew_from_template(template: &CrewTemplate, owner: Identity, level: u8) -> Crew {
+    Crew {
+        id: 0,
+        owner,
+        template_id: template.id,
+        name: template.name.clone(),
+        rarity: template.rarity,
+        traits: template.traits.clone(),
+        max_hp: scale_stat(template.max_hp, level),
+        ability_power: scale_stat(template.ability_power, level),
+        attack: scale_stat(template.attack, level),
+        attack_speed: template.attack_speed,
+        defense: template.defense,
+        magic_resistance: template.magic_resistance,
+        cost: template.cost,
+        level,
+        slot_index: None,
+        bench_index: None,
+        item1: None,
+        item2: None,
+        item3: None,
+    }
+}
+
+/// Create a shop offer of a template at level 1 stats
+pub fn shop_crew_from_template(template: &CrewTemplate, player: Identity) -> ShopCrew {
+    ShopCrew {
+        id: 0,
+        player,
+        template_id: template.id,
+        name: template.name.clone(),
+        rarity: template.rarity,
+        traits: template.traits.clone(),
+        max_hp: template.max_hp,
+        ability_power: template.ability_power,
+        attack: template.attack,
+        attack_speed: template.attack_speed,
+        defense: template.defense,
+        magic_resistance: template.magic_resistance,
+        cost: template.cost,
+    }
+}
+
+/// Check that a template is playable: named, with sane stats and a few distinct traits
+pub fn validate_crew_template(template: &CrewTemplate) -> Result<(), String> {
+    if template.name.trim().is_empty() {
+        return Err("Name cannot be empty".to_string());
+    }
+    if template.traits.is_empty() || template.traits.len() > MAX_CREW_TRAITS {
+        return Err(format!("A crew needs 1 to {} traits", MAX_CREW_TRAITS));
+    }
+    if template.traits.iter().enumerate().any(|(i, t)| template.traits[..i].contains(t)) {
+        return Err("Traits must be unique".to_string());
+    }
+    if template.max_hp == 0 {
+        return Err("Max HP must be at least 1".to_string());
+    }
+    if !template.attack_speed.is_finite() || template.attack_speed < MIN_ATTACK_SPEED {
+        return Err(format!("Attack speed must be at least {}", MIN_ATTACK_SPEED));
+    }
+    if template.cost == 0 {
+        return Err("Cost must be at least 1".to_string());
+    }
+    Ok(())
+}
+
+/// Push an edited template to existing rows derived from it, according to the policy
+pub fn resync_crew_template(ctx: &ReducerContext, template: &CrewTemplate, policy: TemplateSyncPolicy) {
+    if policy == TemplateSyncPolicy::KeepExisting {
+        return;
+    }
+
+    for offer in ctx.db.shop_crew().template_id().filter(template.id) {
+        ctx.db.shop_crew().id().update(ShopCrew {
+            id: offer.id,
+            ..shop_crew_from_template(template, offer.player)
+        });
+    }
+
+    if policy == TemplateSyncPolicy::ResyncAll {
+        for crew in ctx.db.crew().template_id().filter(template.id) {
+            // Keep identity, level, placement and items - only template-derived values change
+            let crew = ctx.db.crew().id().update(Crew {
+                id: crew.id,
+                slot_index: crew.slot_index,
+                bench_index: crew.bench_index,
+                item1: crew.item1,
+                item2: crew.item2,
+                item3: crew.item3,
+                ..crew_from_template(template, crew.owner, crew.level)
+            });
+            sync_effective_stats(ctx, &crew);
+        }
+    }
+}
+
+/// The crew roster from the embedded data file
+pub fn crew_templates() -> Result<Vec<CrewTemplate>, String> {
+    parse_crew_templates(CREW_TEMPLATES_JSON)
+        .map_err(|e| format!("Embedded crew templates are invalid: {}", e))
 }
@@ -0,0 +1,76 @@
+use spacetimedb::{Identity, ReducerContext, log};
+use crate::types::*;
+use crate::tables::*;
+use crate::systems::pool::*;
+use crate::systems::matchmaking::*;
+use crate::systems::shop::*;
+
+/// HP a player loses to a battle of the given round, given the rarity and level of every enemy unit left standing
+pub fn battle_damage(round: u32, survivors: &[(CrewRarity, u8)]) -> u8 {
+    let round_damage = (1 + round / ROUNDS_PER_ROUND_DAMAGE).min(MAX_ROUND_DAMAGE);
+    let unit_power: u32 = survivors.iter()
+        .map(|(rarity, level)| rarity.damage() + level.saturating_sub(1) as u32)
+        .sum();
+    (round_damage + unit_power / UNIT_POWER_PER_DAMAGE).min(STARTING_PLAYER_HP as u32) as u8
+}
+
+/// Players who are out of the game can only watch
+pub fn ensure_not_eliminated(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    match ctx.db.player().identity().find(identity) {
+        Some(player) if player.eliminated => Err("You have been eliminated".to_string()),
+        _ => Ok(()),
+    }
+}
+
+/// Knock a player out of their lobby: their crew go back to the pool and their placement is recorded.
+/// When a single player is left standing they win and the lobby's game ends.
+/// The global queue never ends, so a player knocked out there starts over with a fresh board.
+pub fn eliminate_player(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    let player = ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+    let lobby_id = player_lobby_id(ctx, identity);
+
+    if lobby_id == GLOBAL_LOBBY_ID {
+        reset_player_for_game(ctx, identity)?;
+        roll_shop(ctx, identity)?;
+        log::info!("Player {} was knocked out of the global queue and starts over", identity);
+        return Ok(());
+    }
+
+    let mut remaining: Vec<Player> = lobby_players(ctx, lobby_id).into_iter()
+        .filter(|p| p.identity != identity)
+        .collect();
+    let placement = (remaining.len() + 1).min(u8::MAX as usize) as u8;
+
+    clear_player_board(ctx, identity);
+
+    ctx.db.player().identity().update(Player {
+        eliminated: true,
+        placement: Some(placement),
+        ..player
+    });
+    log::info!("Player {} was eliminated from lobby {} in place {}", identity, lobby_id, placement);
+
+    if remaining.len() == 1 && ctx.db.game_session().lobby_id().find(lobby_id).is_some() {
+        let winner = ctx.db.player().identity().update(Player {
+            placement: Some(1),
+            ..remaining.remove(0)
+        });
+        end_game_session(ctx, lobby_id);
+        log::info!("Player {} won lobby {}", winner.identity, lobby_id);
+    }
+    Ok(())
+}
+
+/// Stop a lobby's game loop
+pub fn end_game_session(ctx: &ReducerContext, lobby_id: u64) {
+    ctx.db.phase_timer().lobby_id().delete(lobby_id);
+    ctx.db.game_session().lobby_id().delete(lobby_id);
+
+    if let Some(lobby) = ctx.db.lobby().id().find(lobby_id) {
+        ctx.db.lobby().id().update(Lobby {
+            status: LobbyStatus::Finished,
+            ..lobby
+        });
+    }
+}
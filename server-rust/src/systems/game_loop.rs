@@ -0,0 +1,165 @@
+use spacetimedb::{Identity, ReducerContext, rand::seq::SliceRandom, ScheduleAt, Table, TimeDuration, log};
+use crate::types::*;
+use crate::tables::*;
+use crate::systems::combat::*;
+use crate::systems::elimination::*;
+use crate::systems::income::*;
+use crate::systems::leveling::*;
+use crate::systems::matchmaking::*;
+use crate::systems::pool::*;
+use crate::systems::shop::*;
+
+/// How long a phase lasts before its deadline moves the session on
+pub fn phase_seconds(phase: GamePhase) -> i64 {
+    match phase {
+        GamePhase::Planning => PLANNING_PHASE_SECONDS,
+        GamePhase::Combat => COMBAT_PHASE_SECONDS,
+        GamePhase::Resolution => RESOLUTION_PHASE_SECONDS,
+    }
+}
+
+/// Round and phase following the given one: a new round starts after resolution
+pub fn next_phase(round: u32, phase: GamePhase) -> (u32, GamePhase) {
+    match phase {
+        GamePhase::Planning => (round, GamePhase::Combat),
+        GamePhase::Combat => (round, GamePhase::Resolution),
+        GamePhase::Resolution => (round + 1, GamePhase::Planning),
+    }
+}
+
+/// Game session of the lobby a player plays in, if a game is running there
+pub fn player_session(ctx: &ReducerContext, identity: Identity) -> Option<GameSession> {
+    ctx.db.game_session().lobby_id().find(player_lobby_id(ctx, identity))
+}
+
+/// Roster edits are only allowed outside of combat, and only while the player is still in the game
+pub fn ensure_roster_editable(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    ensure_not_eliminated(ctx, identity)?;
+
+    match player_session(ctx, identity) {
+        Some(session) if session.phase == GamePhase::Combat => {
+            Err("Cannot change your crew during combat".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Start round 1 of a lobby's game session
+pub fn start_game_session(ctx: &ReducerContext, lobby_id: u64) -> Result<(), String> {
+    if ctx.db.game_session().lobby_id().find(lobby_id).is_some() {
+        return Err("Game already started".to_string());
+    }
+
+    ctx.db.game_session().insert(GameSession {
+        lobby_id,
+        round: 1,
+        phase: GamePhase::Planning,
+        phase_ends_at: ctx.timestamp,
+    });
+    enter_phase(ctx, lobby_id, 1, GamePhase::Planning)?;
+
+    log::info!("Game session started in lobby {}", lobby_id);
+    Ok(())
+}
+
+/// End the current phase of a lobby's session and run the start of the next one
+pub fn advance_phase(ctx: &ReducerContext, lobby_id: u64) -> Result<(), String> {
+    let session = ctx.db.game_session().lobby_id().find(lobby_id)
+        .ok_or("No game running in this lobby")?;
+    let (round, phase) = next_phase(session.round, session.phase);
+    enter_phase(ctx, lobby_id, round, phase)
+}
+
+fn enter_phase(ctx: &ReducerContext, lobby_id: u64, round: u32, phase: GamePhase) -> Result<(), String> {
+    let session = ctx.db.game_session().lobby_id().find(lobby_id)
+        .ok_or("No game running in this lobby")?;
+    let phase_ends_at = ctx.timestamp + TimeDuration::from_micros(phase_seconds(phase) * 1_000_000);
+
+    // Only the deadline of the phase being entered may fire, earlier timers are dropped
+    ctx.db.phase_timer().lobby_id().delete(lobby_id);
+    ctx.db.phase_timer().insert(PhaseTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(phase_ends_at),
+        lobby_id,
+        round,
+        phase,
+    });
+    ctx.db.game_session().lobby_id().update(GameSession {
+        round,
+        phase,
+        phase_ends_at,
+        ..session
+    });
+
+    match phase {
+        GamePhase::Planning => begin_planning(ctx, lobby_id, round),
+        GamePhase::Combat => begin_combat(ctx, lobby_id, round),
+        GamePhase::Resolution => begin_resolution(ctx, lobby_id, round),
+    }?;
+
+    log::info!("Lobby {} entered {:?} of round {}", lobby_id, phase, round);
+    Ok(())
+}
+
+/// Start-of-round step of one player: income, passive XP and a fresh shop
+pub fn start_player_round(ctx: &ReducerContext, identity: Identity, round: u32) -> Result<(), String> {
+    let player = grant_round_income(ctx, identity, round)?;
+    grant_xp(ctx, player, XP_PER_ROUND);
+    auto_refresh_shop(ctx, identity)
+}
+
+fn begin_planning(ctx: &ReducerContext, lobby_id: u64, round: u32) -> Result<(), String> {
+    for player in lobby_players(ctx, lobby_id) {
+        // One player's failed refresh must not hold up the rest of the lobby
+        if let Err(e) = start_player_round(ctx, player.identity, round) {
+            log::info!("Could not start round {} for player {}: {}", round, player.identity, e);
+        }
+    }
+    Ok(())
+}
+
+/// Pair up the lobby's players, avoiding repeat opponents. A player left over fights a copy of another player's board.
+fn begin_combat(ctx: &ReducerContext, lobby_id: u64, round: u32) -> Result<(), String> {
+    let mut players = lobby_players(ctx, lobby_id);
+    players.shuffle(&mut ctx.rng());
+
+    let history: Vec<Battle> = ctx.db.battle().lobby_id().filter(lobby_id).collect();
+    let identities: Vec<Identity> = players.iter().map(|p| p.identity).collect();
+    let pairing = pair_players(&identities, |a, b| times_met(&history, a, b));
+    let find = |identity: Identity| players.iter().find(|p| p.identity == identity);
+
+    for (a, b) in pairing.pairs {
+        if let (Some(player1), Some(player2)) = (find(a), find(b)) {
+            create_battle(ctx, player1, player2, lobby_id, round, false);
+        }
+    }
+
+    if let Some(bye) = pairing.bye.and_then(find) {
+        // The shuffle makes the first other player a random one
+        match players.iter().find(|p| p.identity != bye.identity) {
+            Some(ghost) => {
+                create_battle(ctx, bye, ghost, lobby_id, round, true);
+            }
+            None => log::info!("Player {} has no one to fight in round {}", bye.identity, round),
+        }
+    }
+    Ok(())
+}
+
+/// Finish any fight still running and pay out every battle of the round
+fn begin_resolution(ctx: &ReducerContext, lobby_id: u64, round: u32) -> Result<(), String> {
+    let battles: Vec<Battle> = ctx.db.battle().lobby_id().filter(lobby_id)
+        .filter(|b| b.round == round && !b.settled)
+        .collect();
+
+    for battle in battles {
+        let battle_id = battle.id;
+        if battle.status == BattleStatus::InProgress {
+            force_finish_battle(ctx, battle);
+        }
+        let battle = ctx.db.battle().id().find(battle_id)
+            .ok_or("Battle not found")?;
+        settle_battle(ctx, battle)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use spacetimedb::{Identity, ReducerContext, Table, log};
+use crate::types::*;
+use crate::tables::*;
+
+/// Interest on saved berries: one step per full INTEREST_CHUNK, capped
+pub fn interest_for(berries: u32) -> u32 {
+    (berries / INTEREST_CHUNK * INTEREST_PER_CHUNK).min(MAX_INTEREST)
+}
+
+/// Bonus for a run of consecutive wins or losses, both streaks pay the same
+pub fn streak_bonus(streak: u32) -> u32 {
+    match streak {
+        0..=1 => 0,
+        2..=3 => 100_000,
+        4 => 200_000,
+        _ => 300_000,
+    }
+}
+
+/// Income a player earns at the start of a round, from the berries they saved and their streaks
+pub fn income_breakdown(player: &Player, round: u32) -> RoundIncome {
+    let base = BASE_ROUND_INCOME;
+    let interest = interest_for(player.berries);
+    let streak_bonus = streak_bonus(player.win_streak.max(player.loss_streak));
+
+    RoundIncome {
+        identity: player.identity,
+        round,
+        base,
+        interest,
+        streak_bonus,
+        total: base.saturating_add(interest).saturating_add(streak_bonus),
+    }
+}
+
+/// Pay out a player's round income and publish its breakdown
+pub fn grant_round_income(ctx: &ReducerContext, identity: Identity, round: u32) -> Result<Player, String> {
+    let player = ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+    let income = income_breakdown(&player, round);
+
+    log::info!(
+        "Player {} earned {} berries in round {} ({} interest, {} streak bonus)",
+        identity,
+        income.total,
+        round,
+        income.interest,
+        income.streak_bonus
+    );
+
+    let player = ctx.db.player().identity().update(Player {
+        berries: player.berries.saturating_add(income.total),
+        ..player
+    });
+    if ctx.db.round_income().identity().find(identity).is_some() {
+        ctx.db.round_income().identity().update(income);
+    } else {
+        ctx.db.round_income().insert(income);
+    }
+    Ok(player)
+}
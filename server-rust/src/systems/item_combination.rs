@@ -0,0 +1,85 @@
+use spacetimedb::{ReducerContext, Table, log};
+use crate::types::*;
+use crate::tables::*;
+
+pub const ALL_COMPONENTS: [ItemComponent; 3] = [ItemComponent::Sword, ItemComponent::Ring, ItemComponent::Gloves];
+
+/// Built-in recipes seeded into the recipe table, order of the two components doesn't matter
+pub fn default_recipe(a: ItemComponent, b: ItemComponent) -> Option<CompletedItem> {
+    use ItemComponent::*;
+    match (a, b) {
+        (Sword, Sword) => Some(CompletedItem::Yooru),
+        (Gloves, Gloves) => Some(CompletedItem::Kabuto),
+        (Gloves, Sword) | (Sword, Gloves) => Some(CompletedItem::Shusui),
+        (Ring, Ring) => Some(CompletedItem::RingRing),
+        (Ring, Sword) | (Sword, Ring) => Some(CompletedItem::TenTonHammer),
+        (Ring, Gloves) | (Gloves, Ring) => Some(CompletedItem::ImpactDial),
+    }
+}
+
+/// Seed the recipe table with the built-in recipes - only if it is empty
+pub fn init_item_recipes(ctx: &ReducerContext) {
+    if ctx.db.item_combination_recipe().count() > 0 {
+        log::info!("Item recipes already initialized, skipping...");
+        return;
+    }
+
+    for (i, &component1) in ALL_COMPONENTS.iter().enumerate() {
+        for &component2 in &ALL_COMPONENTS[i..] {
+            if let Some(result) = default_recipe(component1, component2) {
+                ctx.db.item_combination_recipe().insert(ItemCombinationRecipe {
+                    id: 0,
+                    component1,
+                    component2,
+                    result,
+                });
+            }
+        }
+    }
+
+    log::info!("Item recipes initialized with {} recipes", ctx.db.item_combination_recipe().count());
+}
+
+/// Whether a recipe combines exactly these two components, in either order
+pub fn recipe_matches(recipe: &ItemCombinationRecipe, a: ItemComponent, b: ItemComponent) -> bool {
+    (recipe.component1 == a && recipe.component2 == b) || (recipe.component1 == b && recipe.component2 == a)
+}
+
+/// Look up the completed item two components combine into
+pub fn find_recipe(ctx: &ReducerContext, a: ItemComponent, b: ItemComponent) -> Option<CompletedItem> {
+    ctx.db.item_combination_recipe()
+        .iter()
+        .find(|recipe| recipe_matches(recipe, a, b))
+        .map(|recipe| recipe.result)
+}
+
+/// Put an item on a crew member. A component dropped onto a crew already holding a component
+/// combines with it into the recipe's completed item in that slot; otherwise the item takes the
+/// first free slot. Returns the updated crew and the completed item if a combination happened.
+pub fn equip_item(
+    crew: Crew,
+    item: Item,
+    recipe: impl Fn(ItemComponent, ItemComponent) -> Option<CompletedItem>,
+) -> Result<(Crew, Option<CompletedItem>), String> {
+    let mut slots = [crew.item1, crew.item2, crew.item3];
+
+    if let Item::Component(incoming) = item {
+        for slot in slots.iter_mut() {
+            let Some(Item::Component(existing)) = *slot else {
+                continue;
+            };
+            if let Some(result) = recipe(existing, incoming) {
+                *slot = Some(Item::Completed(result));
+                let [item1, item2, item3] = slots;
+                return Ok((Crew { item1, item2, item3, ..crew }, Some(result)));
+            }
+        }
+    }
+
+    let free_slot = slots.iter_mut().find(|slot| slot.is_none())
+        .ok_or("Crew already has 3 items equipped")?;
+    *free_slot = Some(item);
+
+    let [item1, item2, item3] = slots;
+    Ok((Crew { item1, item2, item3, ..crew }, None))
+}
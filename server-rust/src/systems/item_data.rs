@@ -0,0 +1,89 @@
+use spacetimedb::{ReducerContext, Table, log};
+use crate::types::*;
+use crate::tables::*;
+
+/// Default stats of an item component, before any admin tuning
+fn component(component: ItemComponent, bonus_ad: i32, bonus_ap: i32, bonus_attack_speed: f32) -> ItemComponentStats {
+    let item = Item::Component(component);
+    ItemComponentStats {
+        component,
+        name: item.get_name().to_string(),
+        description: item.get_description().to_string(),
+        bonus_ad,
+        bonus_crit_chance: 0.0,
+        bonus_attack_speed,
+        bonus_ap,
+        bonus_armor: 0,
+        bonus_mr: 0,
+        bonus_mana: 0,
+        bonus_hp: 0,
+    }
+}
+
+/// Default stats of a completed item without procs, before any admin tuning
+fn completed(item: CompletedItem, bonus_ad: i32, bonus_ap: i32, bonus_attack_speed: f32) -> CompletedItemStats {
+    let as_item = Item::Completed(item);
+    CompletedItemStats {
+        item,
+        name: as_item.get_name().to_string(),
+        description: as_item.get_description().to_string(),
+        bonus_ad,
+        bonus_crit_chance: 0.0,
+        bonus_crit_damage: 0.0,
+        bonus_attack_speed,
+        bonus_ap,
+        bonus_armor: 0,
+        bonus_mr: 0,
+        bonus_mana: 0,
+        bonus_hp: 0,
+        bonus_hp_regen: 0.0,
+        has_splash: false,
+        armor_shred: 0,
+        proc_every: 0,
+        proc_chance: 0.0,
+        proc_damage: 0,
+        proc_multiplier: 1.0,
+        proc_shots: 1,
+        proc_stun_seconds: 0.0,
+    }
+}
+
+pub fn default_item_component_stats() -> Vec<ItemComponentStats> {
+    vec![
+        component(ItemComponent::Sword, 4, 0, 0.0),
+        component(ItemComponent::Ring, 0, 5, 0.0),
+        component(ItemComponent::Gloves, 0, 0, 0.10),
+    ]
+}
+
+pub fn default_completed_item_stats() -> Vec<CompletedItemStats> {
+    vec![
+        CompletedItemStats { proc_every: 3, proc_damage: 50, ..completed(CompletedItem::Yooru, 15, 0, 0.0) },
+        CompletedItemStats { proc_chance: 0.25, proc_shots: 3, ..completed(CompletedItem::Kabuto, 0, 5, 0.30) },
+        CompletedItemStats { proc_every: 4, proc_multiplier: 2.0, ..completed(CompletedItem::Shusui, 10, 0, 0.15) },
+        CompletedItemStats { proc_damage: 10, ..completed(CompletedItem::RingRing, 0, 13, 0.0) },
+        CompletedItemStats { proc_chance: 0.10, proc_stun_seconds: 1.5, ..completed(CompletedItem::TenTonHammer, 10, 10, 0.0) },
+        CompletedItemStats { proc_damage: 15, ..completed(CompletedItem::ImpactDial, 0, 10, 0.15) },
+    ]
+}
+
+/// Seed the item stat tables - only if they are empty
+pub fn init_item_stats(ctx: &ReducerContext) {
+    if ctx.db.item_component_stats().count() == 0 {
+        for stats in default_item_component_stats() {
+            ctx.db.item_component_stats().insert(stats);
+        }
+    }
+
+    if ctx.db.completed_item_stats().count() == 0 {
+        for stats in default_completed_item_stats() {
+            ctx.db.completed_item_stats().insert(stats);
+        }
+    }
+
+    log::info!(
+        "Item stats initialized: {} components, {} completed items",
+        ctx.db.item_component_stats().count(),
+        ctx.db.completed_item_stats().count()
+    );
+}
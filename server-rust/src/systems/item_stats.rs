@@ -0,0 +1,138 @@
+use spacetimedb::{ReducerContext, Table};
+use battle_core::ItemEffect;
+use crate::types::*;
+use crate::tables::*;
+use crate::systems::item_data::*;
+
+/// Snapshot of the item stat tables, the single source of truth for item numbers
+pub struct ItemCatalog {
+    components: Vec<ItemComponentStats>,
+    completed: Vec<CompletedItemStats>,
+}
+
+impl ItemCatalog {
+    /// Current (admin-tuned) item stats from the database
+    pub fn load(ctx: &ReducerContext) -> Self {
+        ItemCatalog {
+            components: ctx.db.item_component_stats().iter().collect(),
+            completed: ctx.db.completed_item_stats().iter().collect(),
+        }
+    }
+
+    /// The seed item stats, for tools running without a database
+    pub fn defaults() -> Self {
+        ItemCatalog {
+            components: default_item_component_stats(),
+            completed: default_completed_item_stats(),
+        }
+    }
+
+    fn completed_stats(&self, item: CompletedItem) -> Option<&CompletedItemStats> {
+        self.completed.iter().find(|s| s.item == item)
+    }
+
+    /// Stat bonus of one item, zero if the item has no stats row
+    pub fn bonus(&self, item: Item) -> ItemStatBonus {
+        match item {
+            Item::Component(component) => self.components.iter()
+                .find(|s| s.component == component)
+                .map(|s| ItemStatBonus {
+                    attack: s.bonus_ad,
+                    ability_power: s.bonus_ap,
+                    armor: s.bonus_armor,
+                    magic_resist: s.bonus_mr,
+                    mana: s.bonus_mana,
+                    hp: s.bonus_hp,
+                    attack_speed_pct: s.bonus_attack_speed,
+                    crit_chance: s.bonus_crit_chance,
+                    ..ItemStatBonus::default()
+                })
+                .unwrap_or_default(),
+            Item::Completed(completed) => self.completed_stats(completed)
+                .map(|s| ItemStatBonus {
+                    attack: s.bonus_ad,
+                    ability_power: s.bonus_ap,
+                    armor: s.bonus_armor,
+                    magic_resist: s.bonus_mr,
+                    mana: s.bonus_mana,
+                    hp: s.bonus_hp,
+                    attack_speed_pct: s.bonus_attack_speed,
+                    crit_chance: s.bonus_crit_chance,
+                    crit_damage: s.bonus_crit_damage,
+                    hp_regen: s.bonus_hp_regen,
+                    armor_shred: s.armor_shred,
+                    has_splash: s.has_splash,
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Combat proc of a completed item, using the tuned proc numbers
+    pub fn effect(&self, item: CompletedItem) -> Option<ItemEffect> {
+        let s = self.completed_stats(item)?;
+        Some(match item {
+            CompletedItem::Yooru => ItemEffect::Yooru { every: s.proc_every, damage: s.proc_damage },
+            CompletedItem::Kabuto => ItemEffect::Kabuto { chance: s.proc_chance, shots: s.proc_shots },
+            CompletedItem::Shusui => ItemEffect::Shusui { every: s.proc_every, multiplier: s.proc_multiplier },
+            CompletedItem::RingRing => ItemEffect::RingRing { damage: s.proc_damage },
+            CompletedItem::TenTonHammer => ItemEffect::TenTonHammer { chance: s.proc_chance, stun_seconds: s.proc_stun_seconds },
+            CompletedItem::ImpactDial => ItemEffect::ImpactDial { damage: s.proc_damage },
+        })
+    }
+}
+
+/// Items currently equipped by a crew member
+pub fn equipped_items(crew: &Crew) -> impl Iterator<Item = Item> {
+    [crew.item1, crew.item2, crew.item3].into_iter().flatten()
+}
+
+/// Summed stat bonus of every item a crew member holds
+pub fn equipped_bonus(crew: &Crew, catalog: &ItemCatalog) -> ItemStatBonus {
+    equipped_items(crew)
+        .map(|item| catalog.bonus(item))
+        .fold(ItemStatBonus::default(), |acc, b| acc + b)
+}
+
+/// Combat stats of a crew member: its base stats plus every equipped item
+pub fn compute_effective_stats(crew: &Crew, catalog: &ItemCatalog) -> EffectiveStats {
+    let bonus = equipped_bonus(crew, catalog);
+
+    EffectiveStats {
+        crew_id: crew.id,
+        owner: crew.owner,
+        max_hp: apply_bonus(crew.max_hp, bonus.hp).max(1),
+        attack: apply_bonus(crew.attack, bonus.attack),
+        defense: apply_bonus(crew.defense, bonus.armor),
+        ability_power: apply_bonus(crew.ability_power, bonus.ability_power),
+        magic_resistance: apply_bonus(crew.magic_resistance, bonus.magic_resist),
+        attack_speed: crew.attack_speed * (1.0 + bonus.attack_speed_pct),
+        attack_range: if crew.traits.contains(&CrewTrait::Sniper) { UNIT_RANGED_ATTACK_RANGE } else { UNIT_ATTACK_RANGE },
+        crit_chance: (UNIT_BASE_CRIT_CHANCE + bonus.crit_chance).clamp(0.0, 1.0),
+        crit_damage: UNIT_BASE_CRIT_DAMAGE + bonus.crit_damage,
+        starting_mana: apply_bonus(0, bonus.mana).min(UNIT_MAX_MANA),
+        hp_regen: bonus.hp_regen.max(0.0),
+    }
+}
+
+fn upsert_effective_stats(ctx: &ReducerContext, stats: EffectiveStats) {
+    if ctx.db.effective_stats().crew_id().find(stats.crew_id).is_some() {
+        ctx.db.effective_stats().crew_id().update(stats);
+    } else {
+        ctx.db.effective_stats().insert(stats);
+    }
+}
+
+/// Recompute the published effective stats of a crew member (call after its items or stats change)
+pub fn sync_effective_stats(ctx: &ReducerContext, crew: &Crew) {
+    upsert_effective_stats(ctx, compute_effective_stats(crew, &ItemCatalog::load(ctx)));
+}
+
+/// Recompute effective stats of every crew holding an item, after item stats were tuned
+pub fn resync_equipped_effective_stats(ctx: &ReducerContext) {
+    let catalog = ItemCatalog::load(ctx);
+    for crew in ctx.db.crew().iter() {
+        if equipped_items(&crew).next().is_some() {
+            upsert_effective_stats(ctx, compute_effective_stats(&crew, &catalog));
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use spacetimedb::{ReducerContext, Table, log};
+use crate::tables::*;
+
+pub fn default_level_thresholds() -> Vec<LevelThreshold> {
+    [(2, 2), (3, 6), (4, 10), (5, 20), (6, 36), (7, 48), (8, 76), (9, 84)]
+        .into_iter()
+        .map(|(level, xp_required)| LevelThreshold { level, xp_required })
+        .collect()
+}
+
+/// Seed the level thresholds - only if the table is empty
+pub fn init_level_thresholds(ctx: &ReducerContext) {
+    if ctx.db.level_threshold().count() > 0 {
+        return;
+    }
+
+    for threshold in default_level_thresholds() {
+        ctx.db.level_threshold().insert(threshold);
+    }
+    log::info!("Level thresholds initialized: {} levels", ctx.db.level_threshold().count());
+}
+
+/// Threshold of the level right after the given one. A gap in the table ends levelling there.
+pub fn next_threshold(level: u8, thresholds: &[LevelThreshold]) -> Option<&LevelThreshold> {
+    thresholds.iter().find(|t| t.level == level.saturating_add(1))
+}
+
+/// Add XP to a player, levelling up through every threshold it crosses. Returns the new (level, xp).
+/// At the highest level in the table XP no longer accumulates.
+pub fn gain_xp(level: u8, xp: u8, amount: u8, thresholds: &[LevelThreshold]) -> (u8, u8) {
+    let (mut level, mut xp) = (level, xp.saturating_add(amount));
+
+    while let Some(next) = next_threshold(level, thresholds) {
+        if xp < next.xp_required {
+            return (level, xp);
+        }
+        xp -= next.xp_required;
+        level += 1;
+    }
+
+    (level, 0)
+}
+
+pub fn is_max_level(level: u8, thresholds: &[LevelThreshold]) -> bool {
+    next_threshold(level, thresholds).is_none()
+}
+
+/// Crew a player may have on the field at once
+pub fn max_field_crew(player: &Player) -> usize {
+    player.level as usize
+}
+
+pub fn fielded_count(crews: &[Crew]) -> usize {
+    crews.iter().filter(|c| c.slot_index.is_some()).count()
+}
+
+/// Fielded crew that fight, in slot order, dropping any past the field cap
+pub fn field_lineup(crews: &[Crew], max_field: usize) -> Vec<&Crew> {
+    let mut fielded: Vec<&Crew> = crews.iter().filter(|c| c.slot_index.is_some()).collect();
+    fielded.sort_by_key(|c| c.slot_index);
+    fielded.truncate(max_field);
+    fielded
+}
+
+/// Grant XP to a player and persist the resulting level
+pub fn grant_xp(ctx: &ReducerContext, player: Player, amount: u8) -> Player {
+    let thresholds: Vec<LevelThreshold> = ctx.db.level_threshold().iter().collect();
+    let (level, xp) = gain_xp(player.level, player.xp, amount, &thresholds);
+
+    if level > player.level {
+        log::info!("Player {} reached level {}", player.identity, level);
+    }
+    ctx.db.player().identity().update(Player { level, xp, ..player })
+}
@@ -0,0 +1,136 @@
+use spacetimedb::{Identity, ReducerContext, log};
+use crate::types::*;
+use crate::tables::*;
+use crate::systems::combat::*;
+use crate::systems::item_stats::equipped_items;
+use crate::systems::pool::*;
+use crate::systems::roster::*;
+
+/// Opponents of one round: pairs of players, plus the player left over when the count is odd
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pairing<T> {
+    pub pairs: Vec<(T, T)>,
+    pub bye: Option<T>, // Fights a ghost board instead
+}
+
+/// Pair players so that as few pairs as possible have met before, `times_met` counting their earlier battles.
+/// Ties keep the order of `players`, so callers shuffle it first.
+pub fn pair_players<T: Copy>(players: &[T], times_met: impl Fn(T, T) -> u32) -> Pairing<T> {
+    // Trying every pairing is cheap for a lobby (105 ways to pair 8 players), the unbounded global lobby pairs greedily
+    if players.len() > MAX_LOBBY_PLAYERS {
+        return greedy_pairing(players, &times_met);
+    }
+
+    if players.len().is_multiple_of(2) {
+        let (_, pairs) = best_pairs(players, &times_met);
+        return Pairing { pairs, bye: None };
+    }
+
+    let mut best: Option<(u32, Pairing<T>)> = None;
+    for (i, &bye) in players.iter().enumerate() {
+        let mut others = players.to_vec();
+        others.remove(i);
+        let (repeats, pairs) = best_pairs(&others, &times_met);
+        if best.as_ref().is_none_or(|(fewest, _)| repeats < *fewest) {
+            best = Some((repeats, Pairing { pairs, bye: Some(bye) }));
+        }
+    }
+    best.map(|(_, pairing)| pairing).unwrap_or(Pairing { pairs: Vec::new(), bye: None })
+}
+
+/// Pairing of an even number of players with the fewest repeats, and that number of repeats
+fn best_pairs<T: Copy>(players: &[T], times_met: &impl Fn(T, T) -> u32) -> (u32, Vec<(T, T)>) {
+    let Some((&first, rest)) = players.split_first() else {
+        return (0, Vec::new());
+    };
+
+    let mut best: Option<(u32, Vec<(T, T)>)> = None;
+    for i in 0..rest.len() {
+        let mut others = rest.to_vec();
+        let partner = others.remove(i);
+        let (repeats, mut pairs) = best_pairs(&others, times_met);
+        let repeats = repeats + times_met(first, partner);
+        if best.as_ref().is_none_or(|(fewest, _)| repeats < *fewest) {
+            pairs.insert(0, (first, partner));
+            best = Some((repeats, pairs));
+        }
+    }
+    best.unwrap_or((0, Vec::new()))
+}
+
+/// Each player in turn takes the remaining player they met the least
+fn greedy_pairing<T: Copy>(players: &[T], times_met: &impl Fn(T, T) -> u32) -> Pairing<T> {
+    let mut remaining = players.to_vec();
+    let mut pairs = Vec::new();
+
+    while remaining.len() >= 2 {
+        let first = remaining.remove(0);
+        let partner = (0..remaining.len())
+            .min_by_key(|&i| times_met(first, remaining[i]))
+            .unwrap_or(0);
+        pairs.push((first, remaining.remove(partner)));
+    }
+    Pairing { pairs, bye: remaining.pop() }
+}
+
+/// Times two players fought each other in a lobby's current game, ghost battles excluded
+pub fn times_met(battles: &[Battle], a: Identity, b: Identity) -> u32 {
+    battles.iter()
+        .filter(|battle| !battle.ghost)
+        .filter(|battle| {
+            (battle.player1 == a && battle.player2 == Some(b)) || (battle.player1 == b && battle.player2 == Some(a))
+        })
+        .count() as u32
+}
+
+pub fn lobby_members(ctx: &ReducerContext, lobby_id: u64) -> Vec<LobbyMember> {
+    ctx.db.lobby_member().lobby_id().filter(lobby_id).collect()
+}
+
+/// Give a player's crew back to the pool of the lobby they play in, keeping their items, and drop their shop offers
+pub fn clear_player_board(ctx: &ReducerContext, identity: Identity) {
+    let lobby_id = player_lobby_id(ctx, identity);
+
+    for crew in ctx.db.crew().owner().filter(&identity) {
+        return_items_to_inventory(ctx, identity, equipped_items(&crew));
+        return_to_pool(ctx, lobby_id, crew.template_id, copies_in(&crew));
+        delete_crew(ctx, crew.id);
+    }
+    for offer in ctx.db.shop_crew().player().filter(&identity) {
+        ctx.db.shop_crew().id().delete(offer.id);
+    }
+}
+
+/// Fresh start for a new game: empty board, starting berries, HP and level. Career wins, losses and bounty are kept.
+pub fn reset_player_for_game(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    let player = ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+
+    clear_player_board(ctx, identity);
+    ctx.db.round_income().identity().delete(identity);
+    ctx.db.player().identity().update(Player {
+        berries: STARTING_BERRIES,
+        xp: 0,
+        level: 1,
+        hp: STARTING_PLAYER_HP,
+        win_streak: 0,
+        loss_streak: 0,
+        shop_locked: false,
+        eliminated: false,
+        placement: None,
+        ..player
+    });
+    Ok(())
+}
+
+/// Drop the battles and champion pool of a lobby's previous game
+pub fn clear_lobby_game(ctx: &ReducerContext, lobby_id: u64) {
+    let battle_ids: Vec<u64> = ctx.db.battle().lobby_id().filter(lobby_id).map(|b| b.id).collect();
+    for battle_id in battle_ids {
+        clear_battle_units(ctx, battle_id);
+        ctx.db.battle().id().delete(battle_id);
+    }
+    ctx.db.champion_pool().lobby_id().delete(lobby_id);
+
+    log::info!("Cleared the previous game of lobby {}", lobby_id);
+}
@@ -0,0 +1,163 @@
+use spacetimedb::{ReducerContext, Table, log};
+use crate::types::*;
+use crate::tables::*;
+use crate::systems::crew_data::*;
+use crate::systems::item_combination::init_item_recipes;
+use crate::systems::item_data::init_item_stats;
+use crate::systems::item_stats::sync_effective_stats;
+use crate::systems::leveling::init_level_thresholds;
+use crate::systems::pool::ensure_champion_pool;
+use crate::systems::roster::{copies_in, normalize_placements};
+use crate::systems::shop::{init_game_config, init_shop_odds};
+
+/// An ordered data migration. Migrations must be idempotent: a fresh database
+/// seeded by `init` runs all of them too.
+pub struct Migration {
+    pub version: u32,
+    pub description: &'static str,
+    pub run: fn(&ReducerContext),
+}
+
+/// Every migration in order. Append new ones with the next version number, never edit or reorder
+/// shipped ones - e.g. a release that changes data/crew_templates.json adds another `sync_crew_roster`.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration { version: 1, description: "Seed item recipes and item stats", run: seed_item_tables },
+    Migration { version: 2, description: "Link crew and shop offers to their templates", run: link_crew_templates },
+    Migration { version: 3, description: "Sync crew templates with the roster file", run: sync_crew_roster },
+    Migration { version: 4, description: "Backfill effective stats", run: backfill_effective_stats },
+    Migration { version: 5, description: "Separate field and bench placements", run: separate_bench_from_field },
+    Migration { version: 6, description: "Seed player level thresholds", run: init_level_thresholds },
+    Migration { version: 7, description: "Seed shop rarity odds", run: init_shop_odds },
+    Migration { version: 8, description: "Seed the global champion pool minus owned crew", run: seed_global_champion_pool },
+    Migration { version: 9, description: "Seed tunable game rules", run: init_game_config },
+    Migration { version: 10, description: "Mark battles finished before settlement tracking as settled", run: settle_legacy_battles },
+];
+
+/// Version a database reaches once every migration has run
+pub fn latest_schema_version() -> u32 {
+    MIGRATIONS.last().map_or(0, |m| m.version)
+}
+
+pub fn current_schema_version(ctx: &ReducerContext) -> u32 {
+    ctx.db.schema_version().id().find(0).map_or(0, |row| row.version)
+}
+
+/// Apply every migration newer than the stored schema version, recording progress after each one
+pub fn run_migrations(ctx: &ReducerContext) {
+    let current = current_schema_version(ctx);
+    if current >= latest_schema_version() {
+        return;
+    }
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        log::info!("Running migration {}: {}", migration.version, migration.description);
+        (migration.run)(ctx);
+
+        let row = SchemaVersion { id: 0, version: migration.version, migrated_at: ctx.timestamp };
+        if ctx.db.schema_version().id().find(0).is_some() {
+            ctx.db.schema_version().id().update(row);
+        } else {
+            ctx.db.schema_version().insert(row);
+        }
+    }
+
+    log::info!("Schema migrated from version {} to {}", current, latest_schema_version());
+}
+
+// ========== MIGRATIONS ==========
+
+fn seed_item_tables(ctx: &ReducerContext) {
+    init_item_recipes(ctx);
+    init_item_stats(ctx);
+}
+
+/// The template a crew or shop offer created before template ids existed belongs to, matched by name
+pub fn template_named<'a>(templates: &'a [CrewTemplate], name: &str) -> Option<&'a CrewTemplate> {
+    templates.iter().find(|t| t.name.eq_ignore_ascii_case(name))
+}
+
+/// Link a crew created before template ids existed to its template, and take the copy cost from it
+pub fn link_legacy_crew(crew: Crew, templates: &[CrewTemplate]) -> Crew {
+    if crew.template_id != 0 {
+        return crew;
+    }
+    match template_named(templates, &crew.name) {
+        Some(template) => Crew { template_id: template.id, cost: template.cost, ..crew },
+        None => crew,
+    }
+}
+
+/// Crew and shop offers created before template ids existed are matched to a template by name
+fn link_crew_templates(ctx: &ReducerContext) {
+    let templates: Vec<CrewTemplate> = ctx.db.crew_template().iter().collect();
+
+    for crew in ctx.db.crew().template_id().filter(0u64) {
+        let crew = link_legacy_crew(crew, &templates);
+        if crew.template_id != 0 {
+            ctx.db.crew().id().update(crew);
+        }
+    }
+    for offer in ctx.db.shop_crew().template_id().filter(0u64) {
+        if let Some(template) = template_named(&templates, &offer.name) {
+            ctx.db.shop_crew().id().update(ShopCrew { template_id: template.id, ..offer });
+        }
+    }
+}
+
+/// Add new characters and rebalance known ones. Owned crew keep their stats so no progress is lost.
+fn sync_crew_roster(ctx: &ReducerContext) {
+    match crew_templates() {
+        Ok(templates) => {
+            let (added, updated) = import_crew_templates(ctx, templates, TemplateSyncPolicy::ResyncShop);
+            log::info!("Crew roster synced: {} added, {} updated", added, updated);
+        }
+        Err(e) => log::info!("Crew roster not synced: {}", e),
+    }
+}
+
+fn backfill_effective_stats(ctx: &ReducerContext) {
+    for crew in ctx.db.crew().iter() {
+        if ctx.db.effective_stats().crew_id().find(crew.id).is_none() {
+            sync_effective_stats(ctx, &crew);
+        }
+    }
+}
+
+/// Crew bought before the bench model had the field slot copied into the bench index
+fn separate_bench_from_field(ctx: &ReducerContext) {
+    let mut owners: Vec<_> = ctx.db.crew().iter().map(|c| c.owner).collect();
+    owners.sort();
+    owners.dedup();
+
+    for owner in owners {
+        for crew in normalize_placements(ctx.db.crew().owner().filter(&owner).collect()) {
+            ctx.db.crew().id().update(crew);
+        }
+    }
+}
+
+/// Crew bought before the pool existed still hold their copies
+fn seed_global_champion_pool(ctx: &ReducerContext) {
+    if ctx.db.champion_pool().lobby_id().filter(GLOBAL_LOBBY_ID).next().is_some() {
+        return;
+    }
+    ensure_champion_pool(ctx, GLOBAL_LOBBY_ID);
+
+    for entry in ctx.db.champion_pool().lobby_id().filter(GLOBAL_LOBBY_ID) {
+        let owned: u32 = ctx.db.crew().template_id().filter(entry.template_id).map(|c| copies_in(&c)).sum();
+        if owned > 0 {
+            ctx.db.champion_pool().id().update(ChampionPool { remaining: entry.remaining.saturating_sub(owned), ..entry });
+        }
+    }
+}
+
+/// Battles finished before the settled flag existed were paid out when they were completed,
+/// they come through the schema change unsettled with a zero seed
+fn settle_legacy_battles(ctx: &ReducerContext) {
+    let legacy: Vec<Battle> = ctx.db.battle().status().filter(&BattleStatus::Finished)
+        .filter(|b| !b.settled && b.seed == 0)
+        .collect();
+    for battle in legacy {
+        ctx.db.battle().id().update(Battle { settled: true, ..battle });
+    }
+}
@@ -1,5 +1,33 @@
+pub mod access;
+pub mod combat;
 pub mod crew_data;
+pub mod elimination;
+pub mod game_loop;
+pub mod income;
+pub mod item_combination;
+pub mod item_data;
+pub mod item_stats;
+pub mod leveling;
+pub mod matchmaking;
+pub mod migrations;
+pub mod pool;
+pub mod roster;
 pub mod ship_upgrade;
+pub mod shop;
 
+pub use access::*;
+pub use combat::*;
 pub use crew_data::*;
+pub use elimination::*;
+pub use game_loop::*;
+pub use income::*;
+pub use item_combination::*;
+pub use item_data::*;
+pub use item_stats::*;
+pub use leveling::*;
+pub use matchmaking::*;
+pub use migrations::*;
+pub use pool::*;
+pub use roster::*;
 pub use ship_upgrade::*;
+pub use shop::*;
@@ -0,0 +1,74 @@
+use spacetimedb::{Identity, ReducerContext, Table, log};
+use crate::types::*;
+use crate::tables::*;
+
+/// Lobby whose champion pool a player buys from and whose games they play
+pub fn player_lobby_id(ctx: &ReducerContext, player: Identity) -> u64 {
+    ctx.db.lobby_member().identity().find(player)
+        .map(|member| member.lobby_id)
+        .unwrap_or(GLOBAL_LOBBY_ID)
+}
+
+/// Players of a lobby who are still in the game. The global lobby only counts players who are online.
+pub fn lobby_players(ctx: &ReducerContext, lobby_id: u64) -> Vec<Player> {
+    if lobby_id == GLOBAL_LOBBY_ID {
+        return ctx.db.player().iter()
+            .filter(|p| p.online && !p.eliminated && player_lobby_id(ctx, p.identity) == GLOBAL_LOBBY_ID)
+            .collect();
+    }
+
+    ctx.db.lobby_member().lobby_id().filter(lobby_id)
+        .filter_map(|member| ctx.db.player().identity().find(member.identity))
+        .filter(|p| !p.eliminated)
+        .collect()
+}
+
+fn pool_entry(ctx: &ReducerContext, lobby_id: u64, template_id: u64) -> Option<ChampionPool> {
+    ctx.db.champion_pool().lobby_id().filter(lobby_id).find(|p| p.template_id == template_id)
+}
+
+/// Fill a lobby's pool with the full copy count of every template it does not track yet
+pub fn ensure_champion_pool(ctx: &ReducerContext, lobby_id: u64) {
+    for template in ctx.db.crew_template().iter() {
+        if pool_entry(ctx, lobby_id, template.id).is_none() {
+            ctx.db.champion_pool().insert(ChampionPool {
+                id: 0,
+                lobby_id,
+                template_id: template.id,
+                remaining: template.rarity.pool_copies(),
+            });
+        }
+    }
+}
+
+/// Templates with copies left in a lobby's pool
+pub fn available_template_ids(ctx: &ReducerContext, lobby_id: u64) -> Vec<u64> {
+    ctx.db.champion_pool().lobby_id().filter(lobby_id)
+        .filter(|p| p.remaining > 0)
+        .map(|p| p.template_id)
+        .collect()
+}
+
+/// Take one copy of a template out of a lobby's pool, failing if none are left
+pub fn take_from_pool(ctx: &ReducerContext, lobby_id: u64, template_id: u64) -> Result<(), String> {
+    ensure_champion_pool(ctx, lobby_id);
+
+    let entry = pool_entry(ctx, lobby_id, template_id)
+        .ok_or("Crew is not in the champion pool")?;
+    if entry.remaining == 0 {
+        return Err("No copies of this crew left in the pool".to_string());
+    }
+
+    ctx.db.champion_pool().id().update(ChampionPool { remaining: entry.remaining - 1, ..entry });
+    Ok(())
+}
+
+/// Put copies of a template back into a lobby's pool (sold or eliminated crew)
+pub fn return_to_pool(ctx: &ReducerContext, lobby_id: u64, template_id: u64, copies: u32) {
+    match pool_entry(ctx, lobby_id, template_id) {
+        Some(entry) => {
+            ctx.db.champion_pool().id().update(ChampionPool { remaining: entry.remaining + copies, ..entry });
+        }
+        None => log::info!("Template {} is not pooled in lobby {}, {} copies dropped", template_id, lobby_id, copies),
+    }
+}
@@ -0,0 +1,220 @@
+use spacetimedb::{Identity, ReducerContext, Table, log};
+use crate::types::*;
+use crate::tables::*;
+use crate::systems::crew_data::crew_from_template;
+use crate::systems::item_combination::{equip_item, find_recipe};
+use crate::systems::item_stats::{equipped_items, sync_effective_stats};
+
+/// Put items back into a player's inventory
+pub fn return_items_to_inventory(ctx: &ReducerContext, owner: Identity, items: impl IntoIterator<Item = Item>) {
+    for item in items {
+        ctx.db.player_item().insert(PlayerItem {
+            id: 0,
+            owner,
+            item,
+            bench_slot: None,
+        });
+    }
+}
+
+/// Delete a crew row together with its published stats
+pub fn delete_crew(ctx: &ReducerContext, crew_id: u64) {
+    ctx.db.crew().id().delete(crew_id);
+    ctx.db.effective_stats().crew_id().delete(crew_id);
+}
+
+/// Where a crew member stands: a field slot or a bench slot
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Placement {
+    Field(u8),
+    Bench(u8),
+}
+
+impl Placement {
+    pub fn of(crew: &Crew) -> Option<Placement> {
+        match (crew.slot_index, crew.bench_index) {
+            (Some(slot), _) => Some(Placement::Field(slot)),
+            (None, Some(bench)) => Some(Placement::Bench(bench)),
+            (None, None) => None,
+        }
+    }
+
+    pub fn validate(self) -> Result<(), String> {
+        match self {
+            Placement::Field(slot) if slot >= FIELD_SIZE => Err(format!("Invalid field slot (0-{})", FIELD_SIZE - 1)),
+            Placement::Bench(bench) if bench >= BENCH_SIZE => Err(format!("Invalid bench slot (0-{})", BENCH_SIZE - 1)),
+            _ => Ok(()),
+        }
+    }
+
+    /// The crew standing at this placement, clearing the other index
+    pub fn apply(self, crew: Crew) -> Crew {
+        match self {
+            Placement::Field(slot) => Crew { slot_index: Some(slot), bench_index: None, ..crew },
+            Placement::Bench(bench) => Crew { slot_index: None, bench_index: Some(bench), ..crew },
+        }
+    }
+}
+
+pub fn first_free_bench_slot(crews: &[Crew]) -> Option<u8> {
+    (0..BENCH_SIZE).find(|&bench| !crews.iter().any(|c| c.bench_index == Some(bench)))
+}
+
+/// A crew member owned by `owner`
+pub fn owned_crew(ctx: &ReducerContext, owner: Identity, crew_id: u64) -> Result<Crew, String> {
+    let crew = ctx.db.crew().id().find(crew_id)
+        .ok_or("Crew not found")?;
+
+    if crew.owner != owner {
+        return Err("Not your crew".to_string());
+    }
+    Ok(crew)
+}
+
+/// Move a crew member to a placement. A crew already standing there swaps into the mover's old placement.
+pub fn move_crew_to(ctx: &ReducerContext, crew: Crew, target: Placement) -> Result<(), String> {
+    target.validate()?;
+
+    let occupant = ctx.db.crew().owner().filter(&crew.owner)
+        .find(|c| c.id != crew.id && Placement::of(c) == Some(target));
+    if let Some(other) = occupant {
+        let origin = Placement::of(&crew).ok_or("Target slot is occupied")?;
+        ctx.db.crew().id().update(origin.apply(other));
+    }
+
+    ctx.db.crew().id().update(target.apply(crew));
+    Ok(())
+}
+
+/// Give every crew of one owner a unique, valid placement: the field slot or bench slot it already has
+/// if that is free, otherwise the first free bench slot. Crew that do not fit anywhere stay unplaced.
+pub fn normalize_placements(mut crews: Vec<Crew>) -> Vec<Crew> {
+    crews.sort_by_key(|c| c.id);
+    let mut placed: Vec<Crew> = Vec::with_capacity(crews.len());
+
+    for crew in crews {
+        let taken = |placement: Placement| placed.iter().any(|c| Placement::of(c) == Some(placement));
+        let field = crew.slot_index.map(Placement::Field);
+        let bench = crew.bench_index.map(Placement::Bench);
+
+        let placement = [field, bench]
+            .into_iter()
+            .flatten()
+            .find(|&p| p.validate().is_ok() && !taken(p))
+            .or_else(|| first_free_bench_slot(&placed).map(Placement::Bench));
+
+        placed.push(match placement {
+            Some(placement) => placement.apply(crew),
+            None => Crew { slot_index: None, bench_index: None, ..crew },
+        });
+    }
+
+    placed
+}
+
+/// Copies of its template a crew is made of: one per level 1 copy merged into it
+pub fn copies_in(crew: &Crew) -> u32 {
+    (STAR_UP_COPIES as u32).pow(crew.level.saturating_sub(1) as u32)
+}
+
+/// Berries refunded for selling a crew: its full cost for every copy merged into it
+pub fn sell_value(crew: &Crew) -> u32 {
+    crew.cost.saturating_mul(copies_in(crew))
+}
+
+/// Field crew come first (lowest slot), then the bench, so the merged crew stays where it fights
+fn placement_rank(crew: &Crew) -> (bool, u8, bool, u8, u64) {
+    (
+        crew.slot_index.is_none(),
+        crew.slot_index.unwrap_or(0),
+        crew.bench_index.is_none(),
+        crew.bench_index.unwrap_or(0),
+        crew.id,
+    )
+}
+
+/// The next set of copies that merge: STAR_UP_COPIES crew of one template at one level, best placed first.
+/// Lower levels merge first so a fresh merge can chain into the next level.
+pub fn find_star_up(crews: &[Crew]) -> Option<Vec<Crew>> {
+    let mut candidates: Vec<&Crew> = crews.iter().filter(|c| c.level < MAX_CREW_LEVEL).collect();
+    candidates.sort_by_key(|c| (c.level, c.template_id, placement_rank(c)));
+
+    candidates
+        .chunk_by(|a, b| a.level == b.level && a.template_id == b.template_id)
+        .find(|group| group.len() >= STAR_UP_COPIES)
+        .map(|group| group[..STAR_UP_COPIES].iter().map(|c| (*c).clone()).collect())
+}
+
+/// Whether buying one more level 1 copy of a template completes a star-up
+pub fn completes_star_up(crews: &[Crew], template_id: u64) -> bool {
+    crews.iter().filter(|c| c.template_id == template_id && c.level == 1).count() + 1 >= STAR_UP_COPIES
+}
+
+/// Merge copies into the first one, one level up with the template's stats for that level.
+/// Copies of a deleted template scale their own stats up by one level instead.
+/// Every copy's items are re-equipped on the merged crew (combining components), the rest are returned as surplus.
+pub fn merge_copies(
+    copies: &[Crew],
+    template: Option<&CrewTemplate>,
+    recipe: impl Fn(ItemComponent, ItemComponent) -> Option<CompletedItem>,
+) -> (Crew, Vec<Item>) {
+    let keeper = &copies[0];
+    let level = keeper.level + 1;
+
+    let leveled = match template {
+        Some(template) => crew_from_template(template, keeper.owner, level),
+        None => Crew {
+            max_hp: scale_stat(keeper.max_hp, 2),
+            attack: scale_stat(keeper.attack, 2),
+            ability_power: scale_stat(keeper.ability_power, 2),
+            ..keeper.clone()
+        },
+    };
+    let mut merged = Crew {
+        level,
+        max_hp: leveled.max_hp,
+        attack: leveled.attack,
+        ability_power: leveled.ability_power,
+        item1: None,
+        item2: None,
+        item3: None,
+        ..keeper.clone()
+    };
+
+    let mut surplus = Vec::new();
+    for item in copies.iter().flat_map(equipped_items) {
+        match equip_item(merged.clone(), item, &recipe) {
+            Ok((crew, _)) => merged = crew,
+            Err(_) => surplus.push(item),
+        }
+    }
+
+    (merged, surplus)
+}
+
+/// Merge every complete set of copies a player owns. Returns the merged crew.
+pub fn apply_star_ups(ctx: &ReducerContext, owner: Identity) -> Vec<Crew> {
+    let mut merged_crew = Vec::new();
+
+    loop {
+        let crews: Vec<Crew> = ctx.db.crew().owner().filter(&owner).collect();
+        let Some(copies) = find_star_up(&crews) else {
+            break;
+        };
+
+        let template = ctx.db.crew_template().id().find(copies[0].template_id);
+        let (merged, surplus) = merge_copies(&copies, template.as_ref(), |a, b| find_recipe(ctx, a, b));
+        for copy in &copies[1..] {
+            delete_crew(ctx, copy.id);
+        }
+        return_items_to_inventory(ctx, owner, surplus);
+
+        let merged = ctx.db.crew().id().update(merged);
+        sync_effective_stats(ctx, &merged);
+
+        log::info!("Player {} merged {} copies of {} into level {}", owner, STAR_UP_COPIES, merged.name, merged.level);
+        merged_crew.push(merged);
+    }
+
+    merged_crew
+}
@@ -28,7 +28,7 @@ pub fn calculate_ship_type(ctx: &ReducerContext, player: spacetimedb::Identity)
 
 /// Update player's ship type based on active trait level
 pub fn update_player_ship(ctx: &ReducerContext, player_identity: spacetimedb::Identity) {
-    let mut player = match ctx.db.player().identity().find(&player_identity) {
+    let mut player = match ctx.db.player().identity().find(player_identity) {
         Some(p) => p,
         None => return,
     };
@@ -0,0 +1,169 @@
+use spacetimedb::{Identity, ReducerContext, rand::Rng, Table, log};
+use crate::types::*;
+use crate::tables::*;
+use crate::systems::crew_data::shop_crew_from_template;
+use crate::systems::pool::*;
+
+impl ShopOdds {
+    /// Chance in percent of rolling a rarity
+    pub fn weight(&self, rarity: CrewRarity) -> u32 {
+        match rarity {
+            CrewRarity::Common => self.common,
+            CrewRarity::Uncommon => self.uncommon,
+            CrewRarity::Rare => self.rare,
+            CrewRarity::Epic => self.epic,
+            CrewRarity::Legendary => self.legendary,
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        ALL_RARITIES.iter().map(|&r| self.weight(r)).sum()
+    }
+}
+
+pub fn default_shop_odds() -> Vec<ShopOdds> {
+    [
+        (1, [100, 0, 0, 0, 0]),
+        (2, [100, 0, 0, 0, 0]),
+        (3, [75, 25, 0, 0, 0]),
+        (4, [55, 30, 15, 0, 0]),
+        (5, [45, 33, 20, 2, 0]),
+        (6, [30, 40, 25, 5, 0]),
+        (7, [19, 30, 40, 10, 1]),
+        (8, [18, 25, 32, 22, 3]),
+        (9, [10, 20, 25, 35, 10]),
+    ]
+    .into_iter()
+    .map(|(level, [common, uncommon, rare, epic, legendary])| ShopOdds { level, common, uncommon, rare, epic, legendary })
+    .collect()
+}
+
+/// Seed the shop odds - only if the table is empty
+pub fn init_shop_odds(ctx: &ReducerContext) {
+    if ctx.db.shop_odds().count() > 0 {
+        return;
+    }
+
+    for odds in default_shop_odds() {
+        ctx.db.shop_odds().insert(odds);
+    }
+    log::info!("Shop odds initialized for {} levels", ctx.db.shop_odds().count());
+}
+
+/// Odds of a player level: its own row, or the closest configured level below it
+pub fn odds_for_level(odds: &[ShopOdds], level: u8) -> Option<&ShopOdds> {
+    odds.iter().filter(|o| o.level <= level).max_by_key(|o| o.level)
+}
+
+/// Roll one shop offer: first a rarity by the odds, then a template of that rarity.
+/// Rarities without any template are left out of the roll. `roll(n)` returns a uniform value in 0..n.
+pub fn roll_shop_template<'a>(
+    odds: &ShopOdds,
+    templates: &[&'a CrewTemplate],
+    mut roll: impl FnMut(u32) -> u32,
+) -> Option<&'a CrewTemplate> {
+    let weights: Vec<(CrewRarity, u32)> = ALL_RARITIES
+        .iter()
+        .map(|&rarity| (rarity, odds.weight(rarity)))
+        .filter(|&(rarity, weight)| weight > 0 && templates.iter().any(|t| t.rarity == rarity))
+        .collect();
+
+    let total: u32 = weights.iter().map(|(_, weight)| weight).sum();
+    if total == 0 {
+        return None;
+    }
+
+    let mut value = roll(total);
+    let mut rarity = weights[0].0;
+    for &(candidate, weight) in &weights {
+        if value < weight {
+            rarity = candidate;
+            break;
+        }
+        value -= weight;
+    }
+
+    let candidates: Vec<&'a CrewTemplate> = templates.iter().copied().filter(|t| t.rarity == rarity).collect();
+    Some(candidates[roll(candidates.len() as u32) as usize])
+}
+
+/// Current game rules, the defaults if they were never seeded
+pub fn game_config(ctx: &ReducerContext) -> GameConfig {
+    ctx.db.game_config().id().find(0).unwrap_or(GameConfig {
+        id: 0,
+        reroll_cost: DEFAULT_REROLL_COST,
+    })
+}
+
+/// Seed the game rules - only if they do not exist yet
+pub fn init_game_config(ctx: &ReducerContext) {
+    if ctx.db.game_config().id().find(0).is_none() {
+        ctx.db.game_config().insert(game_config(ctx));
+    }
+}
+
+/// Replace a player's shop offers with a fresh roll (free - callers charge for rerolls)
+pub fn roll_shop(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    // Clear old shop
+    for shop_crew in ctx.db.shop_crew().player().filter(&identity) {
+        ctx.db.shop_crew().id().delete(shop_crew.id);
+    }
+
+    // Get crew templates from database (initialized once on server start)
+    let templates: Vec<_> = ctx.db.crew_template().iter().collect();
+
+    if templates.is_empty() {
+        return Err("Crew template database not initialized".to_string());
+    }
+
+    // The player's level decides the rarity odds
+    let player = ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+    let all_odds: Vec<ShopOdds> = ctx.db.shop_odds().iter().collect();
+    let odds = odds_for_level(&all_odds, player.level)
+        .ok_or("Shop odds not configured")?;
+
+    // Only templates with copies left in the lobby's shared pool can be offered
+    let lobby_id = player_lobby_id(ctx, identity);
+    ensure_champion_pool(ctx, lobby_id);
+    let available = available_template_ids(ctx, lobby_id);
+    let templates: Vec<&CrewTemplate> = templates.iter().filter(|t| available.contains(&t.id)).collect();
+
+    // Generate random crew: roll a rarity, then a template of that rarity
+    let mut rng = ctx.rng();
+    for _ in 0..SHOP_SIZE {
+        let template = roll_shop_template(odds, &templates, |n| rng.gen_range(0..n))
+            .ok_or("No crew templates match the shop odds")?;
+
+        ctx.db.shop_crew().insert(shop_crew_from_template(template, identity));
+    }
+
+    Ok(())
+}
+
+/// Take the price of a reroll from the player
+pub fn charge_reroll(mut player: Player, reroll_cost: u32) -> Result<Player, String> {
+    if player.berries < reroll_cost {
+        return Err("Not enough Berries".to_string());
+    }
+
+    player.berries -= reroll_cost;
+    Ok(player)
+}
+
+/// Whether the start of a planning round replaces the player's offers - a locked shop keeps them
+pub fn refreshes_automatically(player: &Player) -> bool {
+    !player.shop_locked
+}
+
+/// Free refresh at the start of a planning round - skipped while the player keeps the shop locked
+pub fn auto_refresh_shop(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    let player = ctx.db.player().identity().find(identity)
+        .ok_or("Player not found")?;
+
+    if !refreshes_automatically(&player) {
+        return Ok(());
+    }
+
+    roll_shop(ctx, identity)
+}
@@ -1,5 +1,6 @@
-use spacetimedb::{Identity, Timestamp};
+use spacetimedb::{Identity, ScheduleAt, Timestamp};
 use crate::types::*;
+use crate::reducers::{battle_tick, phase_deadline};
 
 // ========== TABLES ==========
 
@@ -11,16 +12,26 @@ pub struct Player {
     pub berries: u32,        // Currency
     pub xp: u8,
     pub level: u8,
-    pub hp: u8,
+    pub hp: u8,              // Lost on every battle loss, eliminated at 0
     pub bounty: u32,         // Bounty increases by 100k per win, reset to 0 on loss
     pub wins: u32,
-    pub win_streak: u32,
+    pub win_streak: u32,     // Consecutive wins, reset by a loss
     pub losses: u32,
     pub ship_type: ShipType,
     pub online: bool,
+    // Columns below were added after release, new columns go last with a default so existing rows migrate
+    #[default(0u32)]
+    pub loss_streak: u32,    // Consecutive losses, reset by a win
+    #[default(false)]
+    pub shop_locked: bool,   // Keep the current offers through the next automatic refresh
+    #[default(false)]
+    pub eliminated: bool,
+    #[default(None::<u8>)]
+    pub placement: Option<u8>, // Final standing in the lobby (1 = winner), set once the player is out or has won
 }
 
 #[spacetimedb::table(name = crew, public)]
+#[derive(Clone)]
 pub struct Crew {
     #[primary_key]
     #[auto_inc]
@@ -37,11 +48,49 @@ pub struct Crew {
     pub defense: u32,
     pub magic_resistance: u32,
     pub level: u8,
-    pub slot_index: Option<u8>, // 0-28 on ship/field
-    pub bench_index: Option<u8>, // 0-10 on bench
+    pub slot_index: Option<u8>, // 0..FIELD_SIZE on the field - exactly one of slot/bench index is set
+    pub bench_index: Option<u8>, // 0..BENCH_SIZE on the bench
     pub item1: Option<Item>,
     pub item2: Option<Item>,
     pub item3: Option<Item>,
+    #[index(btree)]
+    #[default(0u64)]
+    pub template_id: u64, // CrewTemplate this crew was bought from, 0 until linked by a migration
+    #[default(0u32)]
+    pub cost: u32, // Berries paid for one copy, the basis of the sell refund
+}
+
+// Berries a player received at the start of their latest round, broken down for the UI
+#[spacetimedb::table(name = round_income, public)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundIncome {
+    #[primary_key]
+    pub identity: Identity,
+    pub round: u32,          // 0 for rounds started outside a game session
+    pub base: u32,
+    pub interest: u32,       // Per INTEREST_CHUNK of berries saved, capped at MAX_INTEREST
+    pub streak_bonus: u32,   // Win or loss streak bonus
+    pub total: u32,
+}
+
+// Combat stats of a crew member after equipped items, kept in sync for the UI
+#[spacetimedb::table(name = effective_stats, public)]
+pub struct EffectiveStats {
+    #[primary_key]
+    pub crew_id: u64,
+    #[index(btree)]
+    pub owner: Identity,
+    pub max_hp: u32,
+    pub attack: u32,
+    pub defense: u32,
+    pub ability_power: u32,
+    pub magic_resistance: u32,
+    pub attack_speed: f32,
+    pub attack_range: f32,
+    pub crit_chance: f32,
+    pub crit_damage: f32,
+    pub starting_mana: u32,
+    pub hp_regen: f32,
 }
 
 // Player's item inventory - items not equipped to any crew
@@ -73,6 +122,9 @@ pub struct ShopCrew {
     pub defense: u32,
     pub magic_resistance: u32,
     pub cost: u32,
+    #[index(btree)]
+    #[default(0u64)]
+    pub template_id: u64, // 0 until linked by a migration
 }
 
 #[spacetimedb::table(name = battle, public)]
@@ -89,9 +141,120 @@ pub struct Battle {
     pub bounty_reward: u32,      // Bounty claimed from loser (set when battle ends)
     pub player1_bounty: u32,     // Player1's bounty at battle start
     pub player2_bounty: u32,     // Player2's bounty at battle start
+    #[default(0u64)]
+    pub seed: u64,               // Combat RNG seed, replaying the snapshot with it reproduces the fight
+    #[default(0u64)]
+    pub rng_state: u64,          // Combat RNG state carried between ticks
+    #[index(btree)]
+    #[default(0u64)]
+    pub lobby_id: u64,           // Lobby whose round created the battle
+    #[default(0u32)]
+    pub round: u32,              // 0 for battles queued outside a game session
+    #[default(false)]
+    pub settled: bool,           // Rewards were paid out
+    #[default(false)]
+    pub ghost: bool,             // player2's board is a copy standing in for a missing opponent
+}
+
+// A group of up to MAX_LOBBY_PLAYERS players playing one game together
+#[spacetimedb::table(name = lobby, public)]
+pub struct Lobby {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,                 // Starts at 1, GLOBAL_LOBBY_ID is never a lobby row
+    pub name: String,
+    pub host: Identity,          // May start the game, passed on when the host leaves
+    pub status: LobbyStatus,
+    pub created_at: Timestamp,
+}
+
+// Lobby a player belongs to - players without a row play in the global lobby
+#[spacetimedb::table(name = lobby_member, public)]
+pub struct LobbyMember {
+    #[primary_key]
+    pub identity: Identity,
+    #[index(btree)]
+    pub lobby_id: u64,
+    pub joined_at: Timestamp,
 }
 
-// Static crew template database - initialized once on server init
+// The game loop of a lobby: rounds of planning, combat and resolution with phase deadlines
+#[spacetimedb::table(name = game_session, public)]
+pub struct GameSession {
+    #[primary_key]
+    pub lobby_id: u64,
+    pub round: u32,
+    pub phase: GamePhase,
+    pub phase_ends_at: Timestamp,
+}
+
+// Ends the current phase of a lobby's game session at its deadline
+#[spacetimedb::table(name = phase_timer, scheduled(phase_deadline))]
+pub struct PhaseTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    #[index(btree)]
+    pub lobby_id: u64,
+    pub round: u32,
+    pub phase: GamePhase,
+}
+
+// Per-battle combat state - one row per fielded crew, spawned when the battle starts
+#[spacetimedb::table(name = battle_unit, public)]
+pub struct BattleUnit {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub battle_id: u64,
+    pub crew_id: u64,
+    pub owner: Identity,
+    pub side: u8,                // 0 = player1, 1 = player2
+    pub rarity: CrewRarity,      // Decides, with level, the damage the unit deals to a losing player
+    pub level: u8,
+    pub position: DbVector2,
+    pub velocity: DbVector2,
+    pub radius: f32,
+    pub max_hp: u32,
+    pub current_hp: u32,
+    pub attack: u32,
+    pub defense: u32,
+    pub ability_power: u32,
+    pub magic_resist: u32,
+    pub attack_speed: f32,       // Attacks per second
+    pub attack_range: f32,
+    pub crit_chance: f32,
+    pub crit_damage: f32,
+    pub max_mana: u32,
+    pub current_mana: u32,
+    pub mana_per_attack: u32,
+    pub attack_cooldown: f32,    // Seconds until next attack
+    pub target_unit_id: Option<u64>,
+    pub ability_ready: bool,
+    pub ability_cooldown: f32,
+    pub is_stunned: bool,
+    pub stun_duration: f32,      // Seconds of stun remaining
+    pub hp_regen: f32,           // HP restored per second
+    pub armor_shred: u32,        // Armor removed from the target on every hit
+    pub has_splash: bool,        // Hits also damage enemies around the target
+    pub attack_count: u32,       // Attacks made this battle, drives every-Nth-attack items
+    pub items: Vec<Item>,        // Items equipped when the battle started
+}
+
+// Scheduled timer driving the simulation of one in-progress battle
+#[spacetimedb::table(name = battle_tick_timer, scheduled(battle_tick))]
+pub struct BattleTickTimer {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    #[index(btree)]
+    pub battle_id: u64,
+}
+
+// Crew template database - seeded on server init, editable by admins
 #[spacetimedb::table(name = crew_template, public)]
 pub struct CrewTemplate {
     #[primary_key]
@@ -109,6 +272,119 @@ pub struct CrewTemplate {
     pub cost: u32,
 }
 
+// Item combination recipes - seeded on init, editable by admins
+#[spacetimedb::table(name = item_combination_recipe, public)]
+pub struct ItemCombinationRecipe {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub component1: ItemComponent,
+    pub component2: ItemComponent,
+    pub result: CompletedItem,
+}
+
+// Tunable component stats - seeded on init, editable by admins
+#[spacetimedb::table(name = item_component_stats, public)]
+pub struct ItemComponentStats {
+    #[primary_key]
+    pub component: ItemComponent,
+    pub name: String,
+    pub description: String,
+    pub bonus_ad: i32,
+    pub bonus_crit_chance: f32,
+    pub bonus_attack_speed: f32,  // 0.10 = +10% attack speed
+    pub bonus_ap: i32,
+    pub bonus_armor: i32,
+    pub bonus_mr: i32,
+    pub bonus_mana: i32,          // Starting mana
+    pub bonus_hp: i32,
+}
+
+// Tunable completed item stats and proc numbers - seeded on init, editable by admins
+#[spacetimedb::table(name = completed_item_stats, public)]
+pub struct CompletedItemStats {
+    #[primary_key]
+    pub item: CompletedItem,
+    pub name: String,
+    pub description: String,
+    pub bonus_ad: i32,
+    pub bonus_crit_chance: f32,
+    pub bonus_crit_damage: f32,
+    pub bonus_attack_speed: f32,  // 0.10 = +10% attack speed
+    pub bonus_ap: i32,
+    pub bonus_armor: i32,
+    pub bonus_mr: i32,
+    pub bonus_mana: i32,          // Starting mana
+    pub bonus_hp: i32,
+    pub bonus_hp_regen: f32,      // HP per second
+    pub has_splash: bool,
+    pub armor_shred: i32,
+    pub proc_every: u32,          // Every Nth attack procs (Yooru, Shusui)
+    pub proc_chance: f32,         // Chance per attack to proc (Kabuto, 10T Hammer)
+    pub proc_damage: u32,         // Flat proc damage (Yooru, RingRing, Impact Dial)
+    pub proc_multiplier: f32,     // Damage multiplier of the proc attack (Shusui)
+    pub proc_shots: u32,          // Shots fired on proc (Kabuto)
+    pub proc_stun_seconds: f32,   // Stun applied on proc (10T Hammer)
+}
+
+// XP needed to reach each player level from the level below - seeded on init, editable by admins
+#[spacetimedb::table(name = level_threshold, public)]
+pub struct LevelThreshold {
+    #[primary_key]
+    pub level: u8,
+    pub xp_required: u8,
+}
+
+// Copies of each crew template left to buy in a lobby, shared by every player in it
+#[spacetimedb::table(name = champion_pool, public)]
+pub struct ChampionPool {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub lobby_id: u64,
+    pub template_id: u64,
+    pub remaining: u32,
+}
+
+// Shop rarity odds in percent for each player level - seeded on init, editable by admins
+#[spacetimedb::table(name = shop_odds, public)]
+pub struct ShopOdds {
+    #[primary_key]
+    pub level: u8,
+    pub common: u32,
+    pub uncommon: u32,
+    pub rare: u32,
+    pub epic: u32,
+    pub legendary: u32,
+}
+
+// Single row of tunable game rules - seeded on init, editable by admins
+#[spacetimedb::table(name = game_config, public)]
+pub struct GameConfig {
+    #[primary_key]
+    pub id: u32, // Always 0
+    pub reroll_cost: u32, // Berries per manual shop refresh
+}
+
+// Single row recording which data migrations have been applied to this database
+#[spacetimedb::table(name = schema_version, public)]
+pub struct SchemaVersion {
+    #[primary_key]
+    pub id: u32, // Always 0
+    pub version: u32,
+    pub migrated_at: Timestamp,
+}
+
+// Identities allowed to call the admin_* reducers - the module owner is seeded at init or first connect
+#[spacetimedb::table(name = admin, public)]
+pub struct Admin {
+    #[primary_key]
+    pub identity: Identity,
+    pub granted_by: Identity,
+    pub granted_at: Timestamp,
+}
+
 // Static pve unit database - initialized once on server init
 #[spacetimedb::table(name = enemy, public)]
 pub struct Enemy {
@@ -1,4 +1,6 @@
 use spacetimedb::SpacetimeType;
+use serde::Deserialize;
+use battle_core::Vec2;
 // ========== MATH TYPES ==========
 
 #[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
@@ -29,6 +31,10 @@ impl DbVector2 {
     pub fn length_sq(&self) -> f32 {
         self.x * self.x + self.y * self.y
     }
+
+    pub fn distance(&self, other: &DbVector2) -> f32 {
+        (*other - *self).magnitude()
+    }
 }
 
 impl std::ops::Add for DbVector2 {
@@ -58,6 +64,18 @@ impl std::ops::Mul<f32> for DbVector2 {
     }
 }
 
+impl From<Vec2> for DbVector2 {
+    fn from(v: Vec2) -> Self {
+        DbVector2::new(v.x, v.y)
+    }
+}
+
+impl From<DbVector2> for Vec2 {
+    fn from(v: DbVector2) -> Self {
+        Vec2::new(v.x, v.y)
+    }
+}
+
 // ========== ENUMS ==========
 
 #[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
@@ -106,7 +124,7 @@ impl ShipType {
     }
 }
 
-#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+#[derive(SpacetimeType, Deserialize, Clone, Copy, Debug, PartialEq)]
 pub enum CrewRarity {
     Common,      // Green 1 Gold
     Uncommon,    // Blue 2 Gold
@@ -115,7 +133,90 @@ pub enum CrewRarity {
     Legendary,   // Gold 5 Gold
 }
 
-#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+impl CrewRarity {
+    /// Copies of each template of this rarity in a lobby's champion pool
+    pub fn pool_copies(self) -> u32 {
+        match self {
+            CrewRarity::Common => 29,
+            CrewRarity::Uncommon => 22,
+            CrewRarity::Rare => 18,
+            CrewRarity::Epic => 12,
+            CrewRarity::Legendary => 10,
+        }
+    }
+
+    /// Power a surviving level 1 unit of this rarity adds to the damage of a loss
+    pub fn damage(self) -> u32 {
+        match self {
+            CrewRarity::Common => 1,
+            CrewRarity::Uncommon => 2,
+            CrewRarity::Rare => 3,
+            CrewRarity::Epic => 4,
+            CrewRarity::Legendary => 5,
+        }
+    }
+}
+
+pub const ALL_RARITIES: [CrewRarity; 5] = [
+    CrewRarity::Common,
+    CrewRarity::Uncommon,
+    CrewRarity::Rare,
+    CrewRarity::Epic,
+    CrewRarity::Legendary,
+];
+
+// What happens to existing crew when an admin edits their crew template
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum TemplateSyncPolicy {
+    KeepExisting, // Only crew rolled after the edit use the new values
+    ResyncShop,   // Open shop offers are updated, owned crew keep their stats
+    ResyncAll,    // Shop offers and owned crew (scaled to their level) are updated
+}
+
+pub const MAX_CREW_TRAITS: usize = 3;
+
+pub const LEVEL_STAT_MULTIPLIER: f32 = 1.8; // Each crew level multiplies HP, attack and AP
+pub const STAR_UP_COPIES: usize = 3;           // Copies of one crew at one level that merge into the next level
+pub const MAX_CREW_LEVEL: u8 = 3;
+
+pub const FIELD_COLUMNS: u8 = 5;
+pub const FIELD_SIZE: u8 = 15; // 3 rows of FIELD_COLUMNS field slots
+pub const BENCH_SIZE: u8 = 9;
+
+pub const XP_PER_ROUND: u8 = 2;        // Granted to every player at the start of each round
+pub const BUY_XP_AMOUNT: u8 = 4;
+pub const BUY_XP_COST: u32 = 400_000;  // Berries
+
+pub const SHOP_SIZE: usize = 5; // Crew offered per shop refresh
+pub const DEFAULT_REROLL_COST: u32 = 200_000; // Berries
+pub const GLOBAL_LOBBY_ID: u64 = 0; // Lobby of players not in any other lobby
+pub const MAX_LOBBY_PLAYERS: usize = 8;
+pub const STARTING_BERRIES: u32 = 1_000_000;
+
+pub const STARTING_PLAYER_HP: u8 = 5;
+pub const ROUNDS_PER_ROUND_DAMAGE: u32 = 5; // The round's share of a loss grows by 1 every this many rounds
+pub const MAX_ROUND_DAMAGE: u32 = 2; // The round's share of a loss stops growing here
+pub const UNIT_POWER_PER_DAMAGE: u32 = 8; // Rarity and level of surviving enemy units per point of damage
+
+pub const BASE_ROUND_INCOME: u32 = 500_000;     // Berries at the start of every planning phase
+pub const INTEREST_CHUNK: u32 = 1_000_000;      // Saved berries per step of interest
+pub const INTEREST_PER_CHUNK: u32 = 100_000;
+pub const MAX_INTEREST: u32 = 500_000;
+pub const PLANNING_PHASE_SECONDS: i64 = 30;
+pub const COMBAT_PHASE_SECONDS: i64 = 65;       // Battles time out after MAX_BATTLE_TICKS (60s)
+pub const RESOLUTION_PHASE_SECONDS: i64 = 5;
+
+/// Stat multiplier of a crew at the given level (level 1 = base stats)
+pub fn level_stat_scale(level: u8) -> f32 {
+    LEVEL_STAT_MULTIPLIER.powi(level.saturating_sub(1) as i32)
+}
+
+/// A level 1 stat scaled to the given level
+pub fn scale_stat(base: u32, level: u8) -> u32 {
+    (base as f32 * level_stat_scale(level)).round() as u32
+}
+
+#[derive(SpacetimeType, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CrewTrait {
     StrawHat,
     Revolutionary,
@@ -168,6 +269,21 @@ impl CrewTrait {
     }
 }
 
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum LobbyStatus {
+    Open,     // Players can join
+    InGame,
+    Finished, // A winner was decided, the host can start a rematch
+}
+
+// Phases of a game round: buy and arrange crew, fight, then settle the results
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum GamePhase {
+    Planning,
+    Combat,
+    Resolution,
+}
+
 #[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
 pub enum BattleStatus {
     WaitingForOpponent,
@@ -261,6 +377,49 @@ impl Item {
     }
 }
 
+/// Stat bonuses granted by equipped items, summed from the item stat tables
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ItemStatBonus {
+    pub attack: i32,
+    pub ability_power: i32,
+    pub armor: i32,
+    pub magic_resist: i32,
+    pub mana: i32,
+    pub hp: i32,
+    pub attack_speed_pct: f32, // 0.10 = +10% attack speed
+    pub crit_chance: f32,
+    pub crit_damage: f32,
+    pub hp_regen: f32,         // HP per second
+    pub armor_shred: i32,
+    pub has_splash: bool,
+}
+
+impl std::ops::Add for ItemStatBonus {
+    type Output = ItemStatBonus;
+
+    fn add(self, other: ItemStatBonus) -> ItemStatBonus {
+        ItemStatBonus {
+            attack: self.attack + other.attack,
+            ability_power: self.ability_power + other.ability_power,
+            armor: self.armor + other.armor,
+            magic_resist: self.magic_resist + other.magic_resist,
+            mana: self.mana + other.mana,
+            hp: self.hp + other.hp,
+            attack_speed_pct: self.attack_speed_pct + other.attack_speed_pct,
+            crit_chance: self.crit_chance + other.crit_chance,
+            crit_damage: self.crit_damage + other.crit_damage,
+            hp_regen: self.hp_regen + other.hp_regen,
+            armor_shred: self.armor_shred + other.armor_shred,
+            has_splash: self.has_splash || other.has_splash,
+        }
+    }
+}
+
+/// Apply a signed bonus to an unsigned stat, never going below zero
+pub fn apply_bonus(base: u32, bonus: i32) -> u32 {
+    (base as i64 + bonus as i64).max(0) as u32
+}
+
 #[derive(SpacetimeType, Clone, Copy, PartialEq)]
 pub enum LocationType {
     Start,
@@ -290,12 +449,5 @@ pub struct EnemySpawn {
 
 // ========== BATTLE CONSTANTS ==========
 
-pub const BATTLE_ARENA_SIZE: f32 = 1600.0; // 1600x1600 battle arena
-pub const BATTLE_TICK_RATE: u32 = 20; // 20 ticks per second (50ms per tick)
-pub const DELTA_TIME: f32 = 1.0 / BATTLE_TICK_RATE as f32; // 0.05 seconds per tick
-
-// Spatial hash grid constants
-pub const GRID_CELL_SIZE: u16 = 200;
-pub const GRID_WIDTH: u16 = 8; // 1600 / 200 = 8
-pub const GRID_HEIGHT: u16 = 8;
-pub const NUM_GRID_CELLS: u16 = GRID_WIDTH * GRID_HEIGHT; // 64 cells
\ No newline at end of file
+// Arena, tick, unit and spatial grid constants live in the combat core
+pub use battle_core::constants::*;
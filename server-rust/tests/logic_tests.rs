@@ -1,70 +1,117 @@
 // Integration tests for pure logic functions (no SpacetimeDB context needed)
 
-// Import the types directly
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct DbVector2 {
-    pub x: f32,
-    pub y: f32,
-}
-
-impl DbVector2 {
-    pub fn new(x: f32, y: f32) -> Self {
-        DbVector2 { x, y }
-    }
+use battle_core::*;
+use battle_with_friends::{compute_effective_stats, ItemCatalog, crew_from_template, crew_templates, CREW_TEMPLATES_JSON, default_recipe, equip_item, parse_crew_templates, validate_crew_template};
+use battle_with_friends::{default_level_thresholds, field_lineup, gain_xp, is_max_level};
+use battle_with_friends::{charge_reroll, default_shop_odds, odds_for_level, refreshes_automatically, roll_shop_template};
+use battle_with_friends::{completes_star_up, copies_in, find_star_up, first_free_bench_slot, link_legacy_crew, merge_copies, normalize_placements, sell_value, Placement};
+use battle_with_friends::{next_phase, phase_seconds};
+use battle_with_friends::{income_breakdown, interest_for, streak_bonus};
+use battle_with_friends::{battle_damage, pair_players};
+use battle_with_friends::bootstrap_admin;
+use battle_with_friends::{CompletedItem, CrewRarity, GamePhase, CrewTrait, Item, ItemComponent, BENCH_SIZE, FIELD_SIZE};
+use spacetimedb::{Identity, Timestamp};
 
-    pub fn normalize(&self) -> DbVector2 {
-        let d2 = self.x * self.x + self.y * self.y;
-        if d2 > 0.0 {
-            let inv_mag = 1.0 / d2.sqrt();
-            DbVector2::new(self.x * inv_mag, self.y * inv_mag)
-        } else {
-            DbVector2::new(0.0, 0.0)
-        }
-    }
+fn test_crew() -> battle_with_friends::Crew {
+    let zoro = crew_templates().unwrap().into_iter().find(|t| t.name == "Zoro").unwrap();
+    crew_from_template(&zoro, Identity::ZERO, 1)
+}
 
-    pub fn magnitude(&self) -> f32 {
-        (self.x * self.x + self.y * self.y).sqrt()
-    }
+/// A Zoro copy with the given id and placement
+fn placed_crew(id: u64, slot_index: Option<u8>, bench_index: Option<u8>) -> battle_with_friends::Crew {
+    let mut crew = test_crew();
+    crew.id = id;
+    crew.slot_index = slot_index;
+    crew.bench_index = bench_index;
+    crew
 }
 
-impl std::ops::Add for DbVector2 {
-    type Output = DbVector2;
-    fn add(self, other: DbVector2) -> DbVector2 {
-        DbVector2::new(self.x + other.x, self.y + other.y)
+fn test_player() -> battle_with_friends::Player {
+    battle_with_friends::Player {
+        identity: Identity::ZERO,
+        name: "Luffy".to_string(),
+        berries: 3_000_000,
+        xp: 0,
+        level: 1,
+        hp: 5,
+        bounty: 0,
+        wins: 0,
+        win_streak: 0,
+        losses: 0,
+        loss_streak: 0,
+        ship_type: battle_with_friends::ShipType::Raft,
+        online: true,
+        shop_locked: false,
+        eliminated: false,
+        placement: None,
     }
 }
 
-impl std::ops::Sub for DbVector2 {
-    type Output = Self;
-    fn sub(self, other: Self) -> Self::Output {
-        Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
+fn test_unit(id: u64, side: u8, x: f32, y: f32) -> CombatUnit {
+    CombatUnit {
+        id,
+        side,
+        position: Vec2::new(x, y),
+        velocity: Vec2::ZERO,
+        radius: UNIT_RADIUS,
+        max_hp: 50,
+        current_hp: 50,
+        attack: 5,
+        defense: 8,
+        ability_power: 10,
+        magic_resist: 5,
+        attack_speed: 1.0,
+        attack_range: UNIT_ATTACK_RANGE,
+        crit_chance: UNIT_BASE_CRIT_CHANCE,
+        crit_damage: UNIT_BASE_CRIT_DAMAGE,
+        max_mana: UNIT_MAX_MANA,
+        current_mana: 0,
+        mana_per_attack: UNIT_MANA_PER_ATTACK,
+        attack_cooldown: 0.0,
+        target_unit_id: None,
+        ability_ready: false,
+        ability_cooldown: 0.0,
+        is_stunned: false,
+        stun_duration: 0.0,
+        hp_regen: 0.0,
+        armor_shred: 0,
+        has_splash: false,
+        attack_count: 0,
+        items: Vec::new(),
     }
 }
 
-impl std::ops::Mul<f32> for DbVector2 {
-    type Output = DbVector2;
-    fn mul(self, scalar: f32) -> DbVector2 {
-        DbVector2::new(self.x * scalar, self.y * scalar)
-    }
+/// Two units already in melee range of each other, never critting
+fn duel(attacker_items: Vec<ItemEffect>) -> BattleSim {
+    let mut attacker = test_unit(1, 0, 500.0, 500.0);
+    attacker.crit_chance = 0.0;
+    attacker.attack = 20;
+    attacker.defense = 0;
+    attacker.items = attacker_items;
+    let mut defender = test_unit(2, 1, 500.0, 580.0);
+    defender.max_hp = 1000;
+    defender.current_hp = 1000;
+    defender.defense = 0;
+    defender.magic_resist = 0;
+    defender.attack = 0;
+    BattleSim::new(vec![attacker, defender], BattleRng::new(3), 0)
 }
 
-pub fn circle_collision(ax: f32, ay: f32, ar: f32, bx: f32, by: f32, br: f32) -> bool {
-    let dx = ax - bx;
-    let dy = ay - by;
-    let dist_sq = dx * dx + dy * dy;
-    let min_dist = ar + br;
-    dist_sq < min_dist * min_dist
+/// Step until the first unit has made `attacks` attacks
+fn run_attacks(sim: &mut BattleSim, attacks: u32) {
+    while sim.units[0].attack_count < attacks {
+        sim.step();
+    }
 }
 
-pub fn get_grid_cell(x: f32, y: f32) -> u16 {
-    const GRID_CELL_SIZE: u16 = 200;
-    const GRID_WIDTH: u16 = 8;
-    let cell_x = ((x / GRID_CELL_SIZE as f32) as u16).min(GRID_WIDTH - 1);
-    let cell_y = ((y / GRID_CELL_SIZE as f32) as u16).min(GRID_WIDTH - 1);
-    cell_y * GRID_WIDTH + cell_x
+fn test_battle(seed: u64) -> BattleSim {
+    let units = vec![
+        test_unit(1, 0, 600.0, 1000.0),
+        test_unit(2, 0, 800.0, 1000.0),
+        test_unit(3, 1, 700.0, 600.0),
+        test_unit(4, 1, 900.0, 400.0),
+    ];
+    BattleSim::new(units, BattleRng::new(seed), 0)
 }
 
 // Tests
@@ -92,7 +139,7 @@ mod tests {
 
     #[test]
     fn test_dbvector2_normalize() {
-        let v = DbVector2::new(3.0, 4.0);
+        let v = Vec2::new(3.0, 4.0);
         let normalized = v.normalize();
         assert!((normalized.x - 0.6).abs() < 0.001);
         assert!((normalized.y - 0.8).abs() < 0.001);
@@ -100,14 +147,14 @@ mod tests {
 
     #[test]
     fn test_dbvector2_magnitude() {
-        let v = DbVector2::new(3.0, 4.0);
+        let v = Vec2::new(3.0, 4.0);
         assert!((v.magnitude() - 5.0).abs() < 0.001);
     }
 
     #[test]
     fn test_dbvector2_operations() {
-        let v1 = DbVector2::new(1.0, 2.0);
-        let v2 = DbVector2::new(3.0, 4.0);
+        let v1 = Vec2::new(1.0, 2.0);
+        let v2 = Vec2::new(3.0, 4.0);
 
         let sum = v1 + v2;
         assert_eq!(sum.x, 4.0);
@@ -132,5 +179,537 @@ mod tests {
 
         // 50 armor should reduce by 33.3%
         assert!((final_damage - 66.66_f32).abs() < 0.1);
+        assert_eq!(calculate_physical_damage(base_damage, 50), 67);
+    }
+
+    #[test]
+    fn test_damage_is_at_least_one() {
+        assert_eq!(calculate_physical_damage(1.0, 500), 1);
+        assert_eq!(calculate_magic_damage(0.0, 0), 1);
+    }
+
+    #[test]
+    fn test_attack_cooldown() {
+        assert!((attack_cooldown(2.0) - 0.5).abs() < 0.001);
+        assert!((attack_cooldown(0.0) - 1.0 / MIN_ATTACK_SPEED).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let mut a = BattleRng::new(42);
+        let mut b = BattleRng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+
+        // Resuming from a saved state continues the same sequence
+        let mut resumed = BattleRng::new(a.state());
+        assert_eq!(resumed.next_u64(), a.next_u64());
+    }
+
+    #[test]
+    fn test_nearest_enemy_uses_grid() {
+        let units = vec![
+            test_unit(1, 0, 100.0, 100.0),
+            test_unit(2, 1, 1500.0, 1500.0),
+            test_unit(3, 1, 300.0, 300.0),
+            test_unit(4, 0, 150.0, 100.0),
+        ];
+        let grid = SpatialGrid::build(&units);
+        assert_eq!(grid.nearest_enemy(&units, 0), Some(2));
+        assert_eq!(grid.units_in_radius(&units, Vec2::new(100.0, 100.0), 20.0), vec![0, 3]);
+    }
+
+    #[test]
+    fn test_units_in_radius_reaches_across_cells() {
+        // The unit's centre is in the next cell, only its radius reaches back over the boundary
+        let units = vec![test_unit(1, 0, GRID_CELL_SIZE as f32 + 20.0, 100.0)];
+        let grid = SpatialGrid::build(&units);
+        assert_eq!(grid.units_in_radius(&units, Vec2::new(GRID_CELL_SIZE as f32 - 10.0, 100.0), 5.0), vec![0]);
+    }
+
+    #[test]
+    fn test_units_in_radius_finds_units_that_moved_after_build() {
+        // Bucketed just before a cell boundary, then moved over it
+        let mut units = vec![test_unit(1, 0, GRID_CELL_SIZE as f32 - 1.0, 100.0)];
+        let grid = SpatialGrid::build(&units).with_drift(UNIT_MOVE_SPEED * DELTA_TIME);
+        units[0].position.x += UNIT_MOVE_SPEED * DELTA_TIME;
+        let center = Vec2::new(units[0].position.x + UNIT_RADIUS + 9.0, 100.0);
+        assert_eq!(grid.units_in_radius(&units, center, 10.0), vec![0]);
+    }
+
+    #[test]
+    fn test_resolve_overlaps_separates_units() {
+        let mut units = vec![test_unit(1, 0, 500.0, 500.0), test_unit(2, 1, 510.0, 500.0)];
+        SpatialGrid::build(&units).resolve_overlaps(&mut units);
+        assert!(units[0].position.distance(&units[1].position) >= UNIT_RADIUS * 2.0 - 0.001);
+    }
+
+    #[test]
+    fn test_battle_replays_identically_from_seed() {
+        let mut first = test_battle(7);
+        let mut second = test_battle(7);
+
+        assert_eq!(first.run(), second.run());
+        assert_eq!(first.tick, second.tick);
+        assert_eq!(first.units, second.units);
+    }
+
+    #[test]
+    fn test_battle_finishes_with_one_side_standing() {
+        let mut sim = test_battle(1);
+        let winner = sim.run();
+        assert!(sim.tick <= MAX_BATTLE_TICKS);
+        assert!(sim.units.iter().filter(|u| u.side != winner).all(|u| !u.is_alive()) || sim.tick == MAX_BATTLE_TICKS);
+    }
+
+    #[test]
+    fn test_shusui_every_fourth_attack_doubles_damage() {
+        let mut sim = duel(vec![ItemEffect::Shusui { every: 4, multiplier: 2.0 }]);
+        run_attacks(&mut sim, 3);
+        assert_eq!(sim.units[1].current_hp, 1000 - 3 * 20);
+        run_attacks(&mut sim, 4);
+        assert_eq!(sim.units[1].current_hp, 1000 - 3 * 20 - 40);
+        assert_eq!(sim.stats.item_procs.get("Shusui"), Some(&1));
+    }
+
+    #[test]
+    fn test_yooru_every_third_attack_hits_all_enemies() {
+        let mut sim = duel(vec![ItemEffect::Yooru { every: 3, damage: 50 }]);
+        let mut far_enemy = test_unit(3, 1, 1500.0, 100.0);
+        far_enemy.magic_resist = 0;
+        far_enemy.attack = 0;
+        sim.units.push(far_enemy);
+
+        run_attacks(&mut sim, 3);
+        assert_eq!(sim.units[2].current_hp, 0);
+        assert_eq!(sim.units[1].current_hp, 1000 - 3 * 20 - 50);
+    }
+
+    #[test]
+    fn test_ten_ton_hammer_stuns_target() {
+        let mut sim = duel(vec![ItemEffect::TenTonHammer { chance: 1.0, stun_seconds: 1.5 }]);
+        run_attacks(&mut sim, 1);
+        // The target already ticked its stun down once this tick
+        assert!(sim.units[1].is_stunned);
+        assert!((sim.units[1].stun_duration - (1.5 - DELTA_TIME)).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_kabuto_only_procs_for_ranged_units() {
+        let mut melee = duel(vec![ItemEffect::Kabuto { chance: 1.0, shots: 3 }]);
+        run_attacks(&mut melee, 1);
+        assert_eq!(melee.units[1].current_hp, 1000 - 20);
+
+        let mut ranged = duel(vec![ItemEffect::Kabuto { chance: 1.0, shots: 3 }]);
+        ranged.units[0].attack_range = UNIT_RANGED_ATTACK_RANGE;
+        run_attacks(&mut ranged, 1);
+        assert_eq!(ranged.units[1].current_hp, 1000 - 3 * 20);
+    }
+
+    #[test]
+    fn test_battle_start_items_fire_on_first_tick() {
+        let mut sim = duel(vec![ItemEffect::ImpactDial { damage: 15 }, ItemEffect::RingRing { damage: 10 }]);
+        sim.units[0].attack_cooldown = 10.0;
+        sim.step();
+        assert_eq!(sim.units[1].current_hp, 1000 - 15 - 10);
+        assert_eq!(sim.stats.item_procs.len(), 2);
+    }
+
+    #[test]
+    fn test_effective_stats_include_items() {
+        let zoro = crew_templates().unwrap().into_iter().find(|t| t.name == "Zoro").unwrap();
+        let mut crew = crew_from_template(&zoro, Identity::ZERO, 1);
+        crew.item1 = Some(Item::Component(ItemComponent::Sword));
+        crew.item2 = Some(Item::Component(ItemComponent::Gloves));
+        crew.item3 = Some(Item::Completed(CompletedItem::TenTonHammer));
+
+        let stats = compute_effective_stats(&crew, &ItemCatalog::defaults());
+        assert_eq!(stats.attack, zoro.attack + 4 + 10);
+        assert_eq!(stats.ability_power, zoro.ability_power + 10);
+        assert!((stats.attack_speed - zoro.attack_speed * 1.10).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_item_catalog_builds_procs_from_stats() {
+        let catalog = ItemCatalog::defaults();
+        assert_eq!(catalog.effect(CompletedItem::Yooru), Some(ItemEffect::Yooru { every: 3, damage: 50 }));
+        assert_eq!(catalog.bonus(Item::Component(ItemComponent::Ring)).ability_power, 5);
+    }
+
+    #[test]
+    fn test_armor_shred_and_splash() {
+        let mut sim = duel(Vec::new());
+        sim.units[0].armor_shred = 3;
+        sim.units[0].has_splash = true;
+        sim.units[1].defense = 10;
+        let mut bystander = test_unit(3, 1, 560.0, 580.0);
+        bystander.defense = 0;
+        bystander.attack = 0;
+        sim.units.push(bystander);
+
+        run_attacks(&mut sim, 1);
+        assert_eq!(sim.units[1].defense, 7);
+        assert!(sim.units[2].current_hp < 50);
+    }
+
+    #[test]
+    fn test_equip_combines_components() {
+        let mut crew = test_crew();
+        crew.item1 = Some(Item::Component(ItemComponent::Ring));
+
+        let (crew, combined) = equip_item(crew, Item::Component(ItemComponent::Sword), default_recipe).unwrap();
+        assert_eq!(combined, Some(CompletedItem::TenTonHammer));
+        assert_eq!(crew.item1, Some(Item::Completed(CompletedItem::TenTonHammer)));
+        assert_eq!(crew.item2, None);
+    }
+
+    #[test]
+    fn test_equip_completed_item_uses_free_slot() {
+        let mut crew = test_crew();
+        crew.item1 = Some(Item::Component(ItemComponent::Ring));
+
+        let (crew, combined) = equip_item(crew, Item::Completed(CompletedItem::Yooru), default_recipe).unwrap();
+        assert_eq!(combined, None);
+        assert_eq!(crew.item2, Some(Item::Completed(CompletedItem::Yooru)));
+    }
+
+    #[test]
+    fn test_equip_rejects_fourth_item() {
+        let mut crew = test_crew();
+        crew.item1 = Some(Item::Completed(CompletedItem::Yooru));
+        crew.item2 = Some(Item::Completed(CompletedItem::Kabuto));
+        crew.item3 = Some(Item::Completed(CompletedItem::Shusui));
+        assert!(equip_item(crew, Item::Component(ItemComponent::Sword), default_recipe).is_err());
+
+        // A full crew can still combine a component into its third slot
+        let mut crew = test_crew();
+        crew.item1 = Some(Item::Completed(CompletedItem::Yooru));
+        crew.item2 = Some(Item::Completed(CompletedItem::Kabuto));
+        crew.item3 = Some(Item::Component(ItemComponent::Gloves));
+        let (crew, combined) = equip_item(crew, Item::Component(ItemComponent::Gloves), default_recipe).unwrap();
+        assert_eq!(combined, Some(CompletedItem::Kabuto));
+        assert_eq!(crew.item3, Some(Item::Completed(CompletedItem::Kabuto)));
+    }
+
+    #[test]
+    fn test_crew_template_validation() {
+        for template in crew_templates().unwrap() {
+            assert!(validate_crew_template(&template).is_ok(), "{} should be valid", template.name);
+        }
+
+        let mut template = crew_templates().unwrap().into_iter().next().unwrap();
+        template.traits = vec![CrewTrait::Sniper, CrewTrait::Sniper];
+        assert!(validate_crew_template(&template).is_err());
+
+        template.traits = vec![CrewTrait::Sniper];
+        template.attack_speed = 0.0;
+        assert!(validate_crew_template(&template).is_err());
+    }
+
+    #[test]
+    fn test_parse_crew_templates() {
+        let zoro = r#"{"name": "Zoro", "rarity": "Rare", "traits": ["Swordsman"], "max_hp": 50, "ability_power": 10,
+            "attack": 4, "attack_speed": 1.0, "defense": 8, "magic_resistance": 5, "cost": 400000}"#;
+        let roster = |entries: &str| format!(r#"{{"note": "test roster", "templates": [{}]}}"#, entries);
+
+        let templates = parse_crew_templates(&roster(zoro)).unwrap();
+        assert_eq!(templates[0].traits, vec![CrewTrait::Swordsman]);
+        assert_eq!(templates[0].cost, 400000);
+
+        assert!(parse_crew_templates(&roster(&format!("{}, {}", zoro, zoro))).is_err());
+        assert!(parse_crew_templates(&roster(&zoro.replace("\"Rare\"", "\"Mythic\""))).is_err());
+        assert!(parse_crew_templates(&roster(&zoro.replace("\"cost\"", "\"art_key\": \"zoro\", \"cost\""))).is_err());
+    }
+
+    #[test]
+    fn test_embedded_crew_roster_parses() {
+        let templates = parse_crew_templates(CREW_TEMPLATES_JSON).unwrap();
+        assert!(templates.iter().any(|t| t.name == "Zoro"));
+        assert_eq!(crew_templates().map(|t| t.len()), Ok(templates.len()));
+    }
+
+    #[test]
+    fn test_star_up_needs_three_copies_and_keeps_field_slot() {
+        let mut crews = vec![placed_crew(1, None, Some(0)), placed_crew(2, Some(4), None)];
+        assert!(find_star_up(&crews).is_none());
+        // Buying the third copy merges it, even onto a full bench
+        assert!(completes_star_up(&crews, crews[0].template_id));
+        assert!(!completes_star_up(&crews[..1], crews[0].template_id));
+
+        crews.push(placed_crew(3, None, Some(1)));
+        let copies = find_star_up(&crews).unwrap();
+        assert_eq!(copies.iter().map(|c| c.id).collect::<Vec<_>>(), vec![2, 1, 3]);
+
+        // Another template or level does not count as a copy
+        crews[2].level = 2;
+        assert!(find_star_up(&crews).is_none());
+        crews[2].level = 1;
+        crews[2].template_id = 7;
+        assert!(find_star_up(&crews).is_none());
+    }
+
+    #[test]
+    fn test_merge_copies_scales_stats_and_returns_surplus_items() {
+        let mut copies = vec![placed_crew(1, Some(0), None), placed_crew(2, None, Some(0)), placed_crew(3, None, Some(1))];
+        copies[0].item1 = Some(Item::Completed(CompletedItem::Yooru));
+        copies[0].item2 = Some(Item::Completed(CompletedItem::Kabuto));
+        copies[1].item1 = Some(Item::Component(ItemComponent::Sword));
+        copies[2].item1 = Some(Item::Component(ItemComponent::Ring));
+        copies[2].item2 = Some(Item::Completed(CompletedItem::Shusui));
+
+        let zoro = crew_templates().unwrap().into_iter().find(|t| t.name == "Zoro").unwrap();
+        let (merged, surplus) = merge_copies(&copies, Some(&zoro), default_recipe);
+        assert_eq!(merged.id, 1);
+        assert_eq!(merged.level, 2);
+        assert_eq!(merged.slot_index, Some(0));
+        assert_eq!(merged.max_hp, crew_from_template(&zoro, Identity::ZERO, 2).max_hp);
+        // Sword + Ring combine into a hammer in the third slot, the Shusui no longer fits
+        assert_eq!(merged.item3, Some(Item::Completed(CompletedItem::TenTonHammer)));
+        assert_eq!(surplus, vec![Item::Completed(CompletedItem::Shusui)]);
+
+        // A second merge lands on the same stats as a crew created at level 3
+        let copies = vec![merged.clone(), merged.clone(), merged];
+        let (merged, _) = merge_copies(&copies, Some(&zoro), default_recipe);
+        let created = crew_from_template(&zoro, Identity::ZERO, 3);
+        assert_eq!((merged.max_hp, merged.attack, merged.ability_power), (created.max_hp, created.attack, created.ability_power));
+    }
+
+    #[test]
+    fn test_sell_value_counts_merged_copies() {
+        let mut crew = test_crew();
+        assert_eq!(copies_in(&crew), 1);
+        assert_eq!(sell_value(&crew), crew.cost);
+        crew.level = 2;
+        assert_eq!(sell_value(&crew), crew.cost * 3);
+        crew.level = 3;
+        assert_eq!(copies_in(&crew), 9);
+        assert_eq!(sell_value(&crew), crew.cost * 9);
+    }
+
+    #[test]
+    fn test_placement_keeps_one_index() {
+        let crew = Placement::Bench(2).apply(placed_crew(1, Some(3), None));
+        assert_eq!((crew.slot_index, crew.bench_index), (None, Some(2)));
+        assert_eq!(Placement::of(&crew), Some(Placement::Bench(2)));
+
+        assert!(Placement::Field(FIELD_SIZE - 1).validate().is_ok());
+        assert!(Placement::Field(FIELD_SIZE).validate().is_err());
+        assert!(Placement::Bench(BENCH_SIZE).validate().is_err());
+    }
+
+    #[test]
+    fn test_first_free_bench_slot() {
+        let crews = vec![placed_crew(1, None, Some(0)), placed_crew(2, Some(0), None), placed_crew(3, None, Some(1))];
+        assert_eq!(first_free_bench_slot(&crews), Some(2));
+
+        let full: Vec<_> = (0..BENCH_SIZE).map(|b| placed_crew(b as u64 + 1, None, Some(b))).collect();
+        assert_eq!(first_free_bench_slot(&full), None);
+    }
+
+    #[test]
+    fn test_normalize_placements_resolves_duplicates() {
+        let crews = normalize_placements(vec![
+            placed_crew(1, Some(4), Some(4)), // Old buy_crew wrote the slot into both indices
+            placed_crew(2, Some(4), Some(4)),
+            placed_crew(3, None, None),
+        ]);
+
+        assert_eq!(Placement::of(&crews[0]), Some(Placement::Field(4)));
+        assert_eq!(Placement::of(&crews[1]), Some(Placement::Bench(4)));
+        assert_eq!(Placement::of(&crews[2]), Some(Placement::Bench(0)));
+    }
+
+    #[test]
+    fn test_migrations_upgrade_legacy_crew_rows() {
+        let templates: Vec<_> = crew_templates().unwrap().into_iter().enumerate()
+            .map(|(i, t)| battle_with_friends::CrewTemplate { id: i as u64 + 1, ..t })
+            .collect();
+        let zoro = templates.iter().find(|t| t.name == "Zoro").unwrap();
+
+        // Rows from before the series: the new columns hold their defaults, the slot is in both indices
+        let legacy = |id: u64, name: &str, slot: u8| battle_with_friends::Crew {
+            id,
+            template_id: 0,
+            cost: 0,
+            name: name.to_string(),
+            slot_index: Some(slot),
+            bench_index: Some(slot),
+            ..test_crew()
+        };
+        let crews: Vec<_> = vec![legacy(1, "zoro", 2), legacy(2, "Zoro", 2), legacy(3, "Nobody", 0)]
+            .into_iter()
+            .map(|crew| link_legacy_crew(crew, &templates))
+            .collect();
+        let crews = normalize_placements(crews);
+
+        assert_eq!((crews[0].template_id, crews[0].cost), (zoro.id, zoro.cost));
+        assert_eq!((crews[1].template_id, crews[1].cost), (zoro.id, zoro.cost));
+        // Unknown names stay unlinked for an admin to sort out
+        assert_eq!((crews[2].template_id, crews[2].cost), (0, 0));
+        assert_eq!(Placement::of(&crews[0]), Some(Placement::Field(2)));
+        assert_eq!(Placement::of(&crews[1]), Some(Placement::Bench(2)));
+        assert_eq!(Placement::of(&crews[2]), Some(Placement::Field(0)));
+
+        // Linking again leaves migrated rows alone
+        assert_eq!(link_legacy_crew(crews[0].clone(), &[]).template_id, zoro.id);
+    }
+
+    #[test]
+    fn test_first_connect_bootstraps_admin_only_without_admins() {
+        // An upgraded database never ran init, so its admin table starts out empty
+        let admin = bootstrap_admin(0, Identity::ONE, Timestamp::UNIX_EPOCH).unwrap();
+        assert_eq!((admin.identity, admin.granted_by), (Identity::ONE, Identity::ONE));
+        assert!(bootstrap_admin(1, Identity::ONE, Timestamp::UNIX_EPOCH).is_none());
+    }
+
+    #[test]
+    fn test_gain_xp_levels_up_through_thresholds() {
+        let thresholds = default_level_thresholds();
+        assert_eq!(gain_xp(1, 0, 1, &thresholds), (1, 1));
+        assert_eq!(gain_xp(1, 1, 1, &thresholds), (2, 0));
+        // 2 XP reaches level 2, the remaining 6 reach level 3
+        assert_eq!(gain_xp(1, 0, 8, &thresholds), (3, 0));
+        assert_eq!(gain_xp(3, 5, 4, &thresholds), (3, 9));
+
+        assert!(!is_max_level(8, &thresholds));
+        assert!(is_max_level(9, &thresholds));
+        assert_eq!(gain_xp(9, 0, 4, &thresholds), (9, 0));
+
+        // Without a level 3 threshold level 2 is as far as it goes, even with level 4 configured
+        let gapped: Vec<_> = thresholds.into_iter().filter(|t| t.level != 3).collect();
+        assert!(is_max_level(2, &gapped));
+        assert_eq!(gain_xp(2, 0, 30, &gapped), (2, 0));
+    }
+
+    #[test]
+    fn test_field_lineup_is_capped_in_slot_order() {
+        let crews = vec![
+            placed_crew(1, Some(4), None),
+            placed_crew(2, None, Some(0)),
+            placed_crew(3, Some(0), None),
+            placed_crew(4, Some(2), None),
+        ];
+        let ids = |lineup: Vec<&battle_with_friends::Crew>| lineup.iter().map(|c| c.id).collect::<Vec<_>>();
+        assert_eq!(ids(field_lineup(&crews, 9)), vec![3, 4, 1]);
+        assert_eq!(ids(field_lineup(&crews, 2)), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_default_shop_odds_add_up() {
+        let odds = default_shop_odds();
+        assert!(odds.iter().all(|o| o.total() == 100));
+        assert_eq!(odds_for_level(&odds, 1).unwrap().legendary, 0);
+        // Levels past the table use the highest row
+        assert_eq!(odds_for_level(&odds, 12).unwrap().level, 9);
+    }
+
+    #[test]
+    fn test_shop_rolls_rarity_then_template() {
+        let templates = crew_templates().unwrap();
+        let templates: Vec<_> = templates.iter().collect();
+        let odds = default_shop_odds();
+        let level1 = odds_for_level(&odds, 1).unwrap();
+        for n in 0..20 {
+            let template = roll_shop_template(level1, &templates, |range| n % range).unwrap();
+            assert_eq!(template.rarity, CrewRarity::Common);
+        }
+
+        // 75/25 at level 3: rolls 0-74 are Common, 75-99 Uncommon
+        let level3 = odds_for_level(&odds, 3).unwrap();
+        let template = roll_shop_template(level3, &templates, |range| if range == 100 { 80 } else { 0 }).unwrap();
+        assert_eq!(template.rarity, CrewRarity::Uncommon);
+
+        // No template of any rarity with odds
+        let legendary_only: Vec<_> = templates.iter().copied().filter(|t| t.rarity == CrewRarity::Legendary).collect();
+        assert!(roll_shop_template(level1, &legendary_only, |_| 0).is_none());
+    }
+
+    #[test]
+    fn test_phases_cycle_into_next_round() {
+        assert_eq!(next_phase(1, GamePhase::Planning), (1, GamePhase::Combat));
+        assert_eq!(next_phase(1, GamePhase::Combat), (1, GamePhase::Resolution));
+        assert_eq!(next_phase(1, GamePhase::Resolution), (2, GamePhase::Planning));
+        // Fights time out before the combat phase ends
+        assert!(phase_seconds(GamePhase::Combat) * BATTLE_TICK_RATE as i64 > MAX_BATTLE_TICKS as i64);
+    }
+
+    #[test]
+    fn test_reroll_charges_the_reroll_cost() {
+        assert_eq!(charge_reroll(test_player(), 200_000).map(|p| p.berries), Ok(2_800_000));
+
+        let broke = battle_with_friends::Player { berries: 199_999, ..test_player() };
+        assert_eq!(charge_reroll(broke, 200_000).err().as_deref(), Some("Not enough Berries"));
+    }
+
+    #[test]
+    fn test_locked_shop_survives_auto_refresh() {
+        assert!(refreshes_automatically(&test_player()));
+        let locked = battle_with_friends::Player { shop_locked: true, ..test_player() };
+        assert!(!refreshes_automatically(&locked));
+    }
+
+    #[test]
+    fn test_interest_per_saved_chunk_is_capped() {
+        assert_eq!(interest_for(999_999), 0);
+        assert_eq!(interest_for(2_500_000), 200_000);
+        assert_eq!(interest_for(50_000_000), 500_000);
+    }
+
+    #[test]
+    fn test_income_counts_the_longer_streak() {
+        assert_eq!(streak_bonus(1), 0);
+        assert_eq!(streak_bonus(3), 100_000);
+        assert_eq!(streak_bonus(9), 300_000);
+
+        let player = battle_with_friends::Player {
+            losses: 4,
+            loss_streak: 4,
+            ..test_player()
+        };
+        let income = income_breakdown(&player, 5);
+        assert_eq!((income.base, income.interest, income.streak_bonus), (500_000, 300_000, 200_000));
+        assert_eq!(income.total, 1_000_000);
+    }
+
+    #[test]
+    fn test_battle_damage_from_round_and_survivors() {
+        // A wiped-out winner still deals the round damage, which grows to 2 from round 5 on
+        assert_eq!(battle_damage(0, &[]), 1);
+        assert_eq!(battle_damage(4, &[]), 1);
+        assert_eq!(battle_damage(40, &[]), 2);
+        // Level 1 Common = 1, level 3 Legendary = 5 + 2: 8 power is one more point
+        assert_eq!(battle_damage(4, &[(CrewRarity::Common, 1), (CrewRarity::Legendary, 3)]), 2);
+        // A loss never takes more than a full life bar
+        assert_eq!(battle_damage(40, &[(CrewRarity::Legendary, 3); 9]), 5);
+    }
+
+    #[test]
+    fn test_pairing_avoids_repeat_opponents() {
+        // 1-2 and 3-4 already met, so the pairing has to mix them
+        let met = |a: u64, b: u64| u32::from(matches!((a.min(b), a.max(b)), (1, 2) | (3, 4)));
+        let pairing = pair_players(&[1, 2, 3, 4], met);
+        assert_eq!(pairing.bye, None);
+        assert_eq!(pairing.pairs, vec![(1, 3), (2, 4)]);
+        assert!(pairing.pairs.iter().all(|&(a, b)| met(a, b) == 0));
+
+        // Everyone met everyone: any pairing will do
+        let pairing = pair_players(&[1, 2, 3, 4, 5, 6], |_, _| 1);
+        assert_eq!(pairing.pairs.len(), 3);
+    }
+
+    #[test]
+    fn test_odd_player_count_leaves_one_for_a_ghost() {
+        // 1 met both 2 and 3, so 1 takes the ghost board
+        let met = |a: u64, b: u64| u32::from(a.min(b) == 1);
+        let pairing = pair_players(&[1, 2, 3], met);
+        assert_eq!(pairing.bye, Some(1));
+        assert_eq!(pairing.pairs, vec![(2, 3)]);
+
+        // Large global lobbies pair greedily
+        let players: Vec<u64> = (0..11).collect();
+        let pairing = pair_players(&players, |_, _| 0);
+        assert_eq!(pairing.pairs.len(), 5);
+        assert_eq!(pairing.bye, Some(10));
     }
 }